@@ -42,6 +42,12 @@ use {
 ///
 /// </div>
 ///
+/// # Products
+///
+/// If the type is a struct with exactly two fields, this macro also implements the
+/// `LinearizeProduct` trait. This allows maps keyed by the struct to be converted to and
+/// from nested maps keyed by the types of the fields.
+///
 /// # Implementing const functions
 ///
 /// If you want to use the forms of the `static_map` and `static_copy_map` macros that
@@ -56,6 +62,48 @@ use {
 /// In this case, your type must only contain fields that also enabled this attribute. In
 /// particular, you cannot use any of the standard types `u8`, `bool`, etc.
 ///
+/// # Implementing `CheckedBitPattern`
+///
+/// If the type is a fieldless enum with a `#[repr(uN)]` or `#[repr(iN)]` attribute and
+/// default discriminants, you can enable the `bytemuck` attribute to implement
+/// `CheckedBitPattern` from bytemuck 1.x:
+///
+/// ```rust,ignore
+/// #[derive(Linearize, Copy, Clone)]
+/// #[linearize(bytemuck)]
+/// #[repr(u8)]
+/// enum E {
+///     A,
+///     B,
+/// }
+/// ```
+///
+/// A bit pattern is valid if [`from_linear`] returns `Some` for it. This requires the
+/// `bytemuck-1` feature of the linearize crate.
+///
+/// [`from_linear`]: https://docs.rs/linearize/latest/linearize/trait.LinearizeExt.html#tymethod.from_linear
+///
+/// # Checking `TryFromBytes`
+///
+/// zerocopy 0.8.x only allows `TryFromBytes` to be derived. If you derive it for a
+/// fieldless enum with a `#[repr(uN)]` or `#[repr(iN)]` attribute, you can enable the
+/// `zerocopy` attribute to check that the derived validation agrees with `Linearize`:
+///
+/// ```rust,ignore
+/// #[derive(Linearize, TryFromBytes)]
+/// #[linearize(zerocopy)]
+/// #[repr(u8)]
+/// enum E {
+///     A,
+///     B,
+/// }
+/// ```
+///
+/// This fails to compile unless the enum implements `TryFromBytes` and uses default
+/// discriminants. Then a bit pattern is accepted by `TryFromBytes` if and only if
+/// [`from_linear`] returns `Some` for it. This requires the `zerocopy-0_8` feature of
+/// the linearize crate.
+///
 /// # Performance
 ///
 /// If the type is a C-style enum with default discriminants, the derived functions will
@@ -89,8 +137,44 @@ pub fn derive_linearize(input: proc_macro::TokenStream) -> proc_macro::TokenStre
             .predicates
             .push(parse_quote!(#ty: #crate_name::Linearize));
     }
+    let mut bytemuck_impl = quote! {};
+    if input.attributes.enable_bytemuck {
+        bytemuck_impl = match input.build_checked_bit_pattern() {
+            Ok(i) => i,
+            Err(e) => return e.into_compile_error().into(),
+        };
+    }
+    let mut zerocopy_check = quote! {};
+    if input.attributes.enable_zerocopy {
+        zerocopy_check = match input.build_try_from_bytes_check() {
+            Ok(i) => i,
+            Err(e) => return e.into_compile_error().into(),
+        };
+    }
     let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
     let ident = input.ident;
+    let mut product_impl = quote! {};
+    if let Kind::Struct(StructInput { fields }) = &input.kind {
+        if let [first, second] = &fields[..] {
+            let first = &first.ty;
+            let second = &second.ty;
+            product_impl = quote! {
+                // SAFETY:
+                //
+                // By the construction described above, LENGTH is |first| * |second| and
+                // linear(s) = linear(first) * |second| + linear(second).
+                #[automatically_derived]
+                unsafe impl #impl_generics
+                #crate_name::LinearizeProduct for #ident #type_generics
+                #where_clause
+                {
+                    type First = #first;
+
+                    type Second = #second;
+                }
+            };
+        }
+    }
     let mut const_impl = quote! {};
     if input.attributes.enable_const {
         const_impl = quote! {
@@ -159,7 +243,13 @@ pub fn derive_linearize(input: proc_macro::TokenStream) -> proc_macro::TokenStre
                 }
             }
 
+            #product_impl
+
             #const_impl
+
+            #bytemuck_impl
+
+            #zerocopy_check
         };
     };
     res.into()
@@ -172,17 +262,22 @@ struct Input {
     critical_types: Vec<Type>,
     kind: Kind,
     attributes: InputAttributes,
+    repr: Option<Ident>,
 }
 
 struct InputAttributes {
     crate_name: Path,
     enable_const: bool,
+    enable_bytemuck: bool,
+    enable_zerocopy: bool,
 }
 
 #[derive(Default)]
 struct InputAttributesOpt {
     crate_name: Option<Path>,
     enable_const: bool,
+    enable_bytemuck: bool,
+    enable_zerocopy: bool,
 }
 
 enum Kind {
@@ -484,6 +579,7 @@ impl Input {
             critical_types,
             kind: Kind::Enum(EnumInput { variants }),
             attributes: parse_attributes(&input.attrs)?,
+            repr: parse_repr(&input.attrs)?,
         })
     }
 
@@ -506,6 +602,7 @@ impl Input {
             critical_types,
             kind: Kind::Struct(StructInput { fields }),
             attributes: parse_attributes(&input.attrs)?,
+            repr: None,
         })
     }
 
@@ -515,6 +612,125 @@ impl Input {
             Kind::Enum(e) => e.build_linearize(self),
         }
     }
+
+    /// Returns the repr of a fieldless enum and assertions that the discriminant of
+    /// each variant is its linear index.
+    fn build_discriminant_checks(&self, attribute: &str) -> syn::Result<(&Ident, TokenStream)> {
+        let Kind::Enum(e) = &self.kind else {
+            return Err(Error::new(
+                self.span,
+                format!("the {attribute} attribute requires a fieldless enum"),
+            ));
+        };
+        if let Some(v) = e.variants.iter().find(|v| !v.fields.is_empty()) {
+            return Err(Error::new(
+                v.ident.span(),
+                format!("the {attribute} attribute requires a fieldless enum"),
+            ));
+        }
+        let Some(repr) = &self.repr else {
+            return Err(Error::new(
+                self.span,
+                format!(
+                    "the {attribute} attribute requires a #[repr(uN)] or #[repr(iN)] attribute"
+                ),
+            ));
+        };
+        let ident = &self.ident;
+        let variants = e.variants.iter().map(|v| &v.ident);
+        let indices = (0..e.variants.len()).map(|i| LitInt::new(&i.to_string(), Span::call_site()));
+        let message = format!("the {attribute} attribute requires default discriminants");
+        let checks = quote! {
+            #(
+                const _: () = assert!(#ident::#variants as #repr == #indices, #message);
+            )*
+        };
+        Ok((repr, checks))
+    }
+
+    fn build_checked_bit_pattern(&self) -> syn::Result<TokenStream> {
+        let (repr, checks) = self.build_discriminant_checks("bytemuck")?;
+        let crate_name = &self.attributes.crate_name;
+        let ident = &self.ident;
+        let (impl_generics, type_generics, where_clause) = self.generics.split_for_impl();
+        Ok(quote! {
+            // The validity check below assumes that the discriminant of each variant is
+            // its linear index.
+            #checks
+
+            // SAFETY:
+            //
+            // The enum is fieldless and has the layout of #repr. By the assertions above,
+            // the discriminant of each variant is its linear index. Therefore, a bit
+            // pattern is a valid value if and only if it is a linear index, that is, if
+            // from_linear returns Some.
+            #[automatically_derived]
+            unsafe impl #impl_generics
+            #crate_name::__bytemuck_1::CheckedBitPattern for #ident #type_generics
+            #where_clause
+            {
+                type Bits = #repr;
+
+                #[inline]
+                fn is_valid_bit_pattern(bits: &#repr) -> bool {
+                    match usize::try_from(*bits) {
+                        Ok(linear) => {
+                            <Self as #crate_name::LinearizeExt>::from_linear(linear).is_some()
+                        }
+                        Err(_) => false,
+                    }
+                }
+            }
+        })
+    }
+
+    fn build_try_from_bytes_check(&self) -> syn::Result<TokenStream> {
+        let (_, checks) = self.build_discriminant_checks("zerocopy")?;
+        let crate_name = &self.attributes.crate_name;
+        let ident = &self.ident;
+        Ok(quote! {
+            // The derived TryFromBytes accepts exactly the discriminants of the variants.
+            // By these assertions, those are the linear indices.
+            #checks
+
+            const _: () = {
+                fn _assert_try_from_bytes<T>()
+                where
+                    T: #crate_name::__zerocopy_0_8::TryFromBytes,
+                {
+                }
+
+                fn _assert() {
+                    _assert_try_from_bytes::<#ident>();
+                }
+            };
+        })
+    }
+}
+
+fn parse_repr(attrs: &[Attribute]) -> syn::Result<Option<Ident>> {
+    const INTEGERS: [&str; 10] = [
+        "u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize",
+    ];
+    let mut res = None;
+    for attr in attrs {
+        if !attr.meta.path().is_ident("repr") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                if INTEGERS.contains(&ident.to_string().as_str()) {
+                    res = Some(ident.clone());
+                }
+            }
+            if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        })?;
+    }
+    Ok(res)
 }
 
 fn parse_attributes(attrs: &[Attribute]) -> syn::Result<InputAttributes> {
@@ -525,6 +741,8 @@ fn parse_attributes(attrs: &[Attribute]) -> syn::Result<InputAttributes> {
         }
         let new: InputAttributesOpt = attr.meta.require_list()?.parse_args()?;
         res.enable_const |= new.enable_const;
+        res.enable_bytemuck |= new.enable_bytemuck;
+        res.enable_zerocopy |= new.enable_zerocopy;
         macro_rules! opt {
             ($name:ident) => {
                 if new.$name.is_some() {
@@ -537,6 +755,8 @@ fn parse_attributes(attrs: &[Attribute]) -> syn::Result<InputAttributes> {
     Ok(InputAttributes {
         crate_name: res.crate_name.unwrap_or_else(|| parse_quote!(::linearize)),
         enable_const: res.enable_const,
+        enable_bytemuck: res.enable_bytemuck,
+        enable_zerocopy: res.enable_zerocopy,
     })
 }
 
@@ -554,6 +774,12 @@ impl Parse for InputAttributesOpt {
                 "const" => {
                     res.enable_const = true;
                 }
+                "bytemuck" => {
+                    res.enable_bytemuck = true;
+                }
+                "zerocopy" => {
+                    res.enable_zerocopy = true;
+                }
                 _ => {
                     return Err(Error::new(
                         key.span(),
@@ -1,16 +1,35 @@
 #[macro_use]
 mod utils;
 mod arbitrary;
+#[cfg(target_has_atomic = "64")]
+mod atomic;
+mod bits;
+mod borsh;
+mod boxed;
+mod builder;
 mod bytemuck;
 mod copy_map;
+mod counter;
 mod derive;
+mod dfa;
+mod graph;
+mod lazy;
 mod linearize_ext;
 mod linearized;
 mod r#macro;
 mod map;
+mod matrix;
+mod paged;
+mod patch;
+mod permutation;
+mod product;
 mod rand;
+mod range;
+mod range_coder;
 mod serde;
+mod tracked;
 mod variants;
+mod zerocopy;
 
 mod test {
     struct S {
@@ -0,0 +1,62 @@
+use {
+    linearize::{CachePadded, StaticAtomicMap, StaticCopyMap},
+    std::{
+        cmp::Ordering,
+        mem,
+        sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering::Relaxed},
+        thread,
+    },
+};
+
+#[test]
+fn counters() {
+    let map = StaticAtomicMap::<Ordering>::new();
+    thread::scope(|s| {
+        for i in 0..8 {
+            let map = &map;
+            s.spawn(move || {
+                for _ in 0..1000 {
+                    map.fetch_add(&i.cmp(&4), 1, Relaxed);
+                }
+            });
+        }
+    });
+    assert_eq!(map.snapshot(Relaxed), StaticCopyMap([4000, 1000, 3000]));
+    assert_eq!(map.fetch_sub(&Ordering::Equal, 1, Relaxed), 1000);
+    assert_eq!(map.swap(&Ordering::Equal, 5, Relaxed), 999);
+    assert_eq!(map.take(Relaxed), StaticCopyMap([4000, 5, 3000]));
+    assert_eq!(map.snapshot(Relaxed), StaticCopyMap([0, 0, 0]));
+    map.store(&Ordering::Less, 3, Relaxed);
+    assert_eq!(map[Ordering::Less].load(Relaxed), 3);
+    map.reset(Relaxed);
+    assert_eq!(map.load(&Ordering::Less, Relaxed), 0);
+}
+
+#[test]
+fn value_types() {
+    let map = StaticAtomicMap::<bool, AtomicBool>::default();
+    assert!(!map.swap(&true, true, Relaxed));
+    assert_eq!(map.into_inner(), StaticCopyMap([false, true]));
+    let map = StaticAtomicMap::<bool, AtomicUsize>::from_fn(|b| b as usize);
+    assert_eq!(map.fetch_add(&true, 2, Relaxed), 1);
+    assert_eq!(map.get(&true).load(Relaxed), 3);
+    let map = StaticAtomicMap::<bool, AtomicU64>::from(StaticCopyMap([1, 2]));
+    assert_eq!(map.load(&false, Relaxed), 1);
+}
+
+#[test]
+fn padded() {
+    let map = StaticAtomicMap::<bool, CachePadded<AtomicU64>>::new();
+    assert!(mem::size_of_val(&map) >= 128);
+    let a = map.get(&false) as *const _ as usize;
+    let b = map.get(&true) as *const _ as usize;
+    assert!(b - a >= 64);
+    map.fetch_add(&true, 1, Relaxed);
+    assert_eq!(map.snapshot(Relaxed), StaticCopyMap([0, 1]));
+}
+
+#[test]
+fn debug() {
+    let map = StaticAtomicMap::<bool>::from_fn(|b| b as u64);
+    assert_eq!(format!("{:?}", map), "{false: 0, true: 1}");
+}
@@ -0,0 +1,97 @@
+use {
+    linearize::{bit_width, BitError, BitReader, BitWriter, Linearize, StaticCopyMap},
+    std::{cmp::Ordering, convert::Infallible},
+};
+
+#[derive(Linearize, Copy, Clone, Debug, PartialEq)]
+enum Five {
+    A,
+    B,
+    C,
+    D,
+    E,
+}
+
+#[test]
+fn width() {
+    assert_eq!(bit_width::<std::convert::Infallible>(), 0);
+    assert_eq!(bit_width::<()>(), 0);
+    assert_eq!(bit_width::<bool>(), 1);
+    assert_eq!(bit_width::<Five>(), 3);
+    assert_eq!(bit_width::<u16>(), 16);
+    assert_eq!(bit_width::<u32>(), 32);
+}
+
+#[test]
+fn roundtrip_mixed() {
+    let mut buf = [0xff; 16];
+    let mut writer = BitWriter::new(&mut buf);
+    writer.write(&Five::D).unwrap();
+    writer.write(&(u32::MAX - 1)).unwrap();
+    writer.write(&()).unwrap();
+    writer.write(&Ordering::Less).unwrap();
+    writer.write(&0x1234u16).unwrap();
+    writer
+        .write_map(&StaticCopyMap::<Five, bool>::from_fn(|f| f == Five::B))
+        .unwrap();
+    assert_eq!(writer.bits_written(), 3 + 32 + 2 + 16 + 5);
+    assert_eq!(writer.bytes_written(), 8);
+    let len = writer.finish().len();
+    assert_eq!(len, 8);
+    assert_eq!(buf[7] >> 2, 0);
+    let mut reader = BitReader::new(&buf[..len]);
+    assert_eq!(reader.read::<Five>(), Ok(Five::D));
+    assert_eq!(reader.read::<u32>(), Ok(u32::MAX - 1));
+    assert_eq!(reader.read::<()>(), Ok(()));
+    assert_eq!(reader.read::<Ordering>(), Ok(Ordering::Less));
+    assert_eq!(reader.read::<u16>(), Ok(0x1234));
+    assert_eq!(
+        reader.read_map::<Five, bool>(),
+        Ok(StaticCopyMap([false, true, false, false, false]))
+    );
+    assert_eq!(reader.remaining_bits(), 6);
+}
+
+#[test]
+fn unexpected_end() {
+    let mut buf = [0; 1];
+    let mut writer = BitWriter::new(&mut buf);
+    writer.write(&Five::E).unwrap();
+    assert_eq!(writer.write(&0u8), Err(BitError::UnexpectedEnd));
+    assert_eq!(
+        writer.write_map(&StaticCopyMap::<bool, Five>([Five::A, Five::B])),
+        Err(BitError::UnexpectedEnd)
+    );
+    assert_eq!(writer.bits_written(), 3);
+    let mut reader = BitReader::new(&buf);
+    assert_eq!(reader.read::<u16>(), Err(BitError::UnexpectedEnd));
+    assert_eq!(reader.bits_read(), 0);
+}
+
+#[test]
+fn empty_map() {
+    let mut buf = [0; 1];
+    let mut writer = BitWriter::new(&mut buf);
+    writer.write(&0u8).unwrap();
+    assert_eq!(
+        writer.write_map(&StaticCopyMap::<Infallible, u8>([])),
+        Ok(())
+    );
+    assert_eq!(writer.bits_written(), 8);
+    let mut reader = BitReader::new(&buf);
+    reader.read::<u8>().unwrap();
+    assert_eq!(reader.read_map::<Infallible, u8>(), Ok(StaticCopyMap([])));
+    assert_eq!(reader.bits_read(), 8);
+}
+
+#[test]
+fn invalid_value() {
+    let buf = [0b111];
+    let mut reader = BitReader::new(&buf);
+    assert_eq!(reader.read::<Five>(), Err(BitError::InvalidValue));
+    assert_eq!(reader.bits_read(), 0);
+    assert_eq!(reader.read_map::<bool, Five>(), Err(BitError::InvalidValue));
+    assert_eq!(reader.bits_read(), 0);
+    assert_eq!(reader.read::<Ordering>(), Err(BitError::InvalidValue));
+    assert_eq!(reader.read::<bool>(), Ok(true));
+}
@@ -0,0 +1,73 @@
+use {
+    borsh::{
+        schema::{BorshSchemaContainer, Definition},
+        BorshDeserialize, BorshSchema, BorshSerialize,
+    },
+    linearize::{Linearize, StaticCopyMap, StaticMap},
+};
+
+#[derive(Linearize, Debug, PartialEq)]
+enum Channel {
+    Red,
+    Green,
+    Blue,
+}
+
+#[test]
+fn roundtrip() {
+    let map = StaticMap::<Channel, u16>([1, 2, 0x300]);
+    let bytes = borsh::to_vec(&map).unwrap();
+    assert_eq!(bytes, [1, 0, 2, 0, 0, 3]);
+    assert_eq!(bytes, borsh::to_vec(&[1u16, 2, 0x300]).unwrap());
+    assert_eq!(
+        borsh::from_slice::<StaticMap<Channel, u16>>(&bytes).unwrap(),
+        map
+    );
+    assert!(borsh::from_slice::<StaticMap<Channel, u16>>(&bytes[..5]).is_err());
+    assert!(borsh::from_slice::<StaticMap<Channel, u16>>(&[0; 7]).is_err());
+
+    let copy = StaticCopyMap::<bool, u8>([4, 5]);
+    let bytes = borsh::to_vec(&copy).unwrap();
+    assert_eq!(bytes, [4, 5]);
+    assert_eq!(
+        borsh::from_slice::<StaticCopyMap<bool, u8>>(&bytes).unwrap(),
+        copy
+    );
+}
+
+#[test]
+fn nested() {
+    #[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq)]
+    struct Packet {
+        id: u8,
+        map: StaticMap<bool, Option<u8>>,
+    }
+    let packet = Packet {
+        id: 7,
+        map: StaticMap([None, Some(9)]),
+    };
+    let bytes = borsh::to_vec(&packet).unwrap();
+    assert_eq!(bytes, [7, 0, 1, 9]);
+    assert_eq!(borsh::from_slice::<Packet>(&bytes).unwrap(), packet);
+}
+
+#[test]
+fn schema() {
+    let container = BorshSchemaContainer::for_type::<StaticMap<Channel, u32>>();
+    let declaration = format!("StaticMap<{}, u32>", std::any::type_name::<Channel>());
+    assert_eq!(container.declaration(), &declaration);
+    assert_eq!(
+        container.get_definition(&declaration),
+        Some(&Definition::Sequence {
+            length_width: 0,
+            length_range: 3..=3,
+            elements: "u32".to_string(),
+        })
+    );
+    assert!(container.validate().is_ok());
+    assert_eq!(StaticMap::<bool, u8>::declaration(), "StaticMap<bool, u8>");
+    assert_eq!(
+        StaticCopyMap::<bool, u8>::declaration(),
+        StaticMap::<bool, u8>::declaration()
+    );
+}
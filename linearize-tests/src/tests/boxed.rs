@@ -0,0 +1,74 @@
+use {
+    linearize::{BoxedStaticMap, StaticMap},
+    std::{cell::Cell, cmp::Ordering, panic, rc::Rc},
+};
+
+// Large enough to overflow the stack of test threads if it were built on the stack.
+type Large = StaticMap<u16, [u64; 32]>;
+
+#[test]
+fn large() {
+    let map = Large::boxed_from_fn(|l| [l as u64; 32]);
+    assert_eq!(map[0x1234], [0x1234; 32]);
+    let map = Large::new_boxed_default();
+    assert!(map.values().all(|v| *v == [0; 32]));
+    let mut map = BoxedStaticMap::<u16, [u64; 32]>::default();
+    map[u16::MAX][31] = 1;
+    let clone = map.clone();
+    assert_eq!(clone[u16::MAX][31], 1);
+    assert!(clone == map);
+}
+
+#[test]
+fn from_vec() {
+    let vec = vec![1, 2, 3];
+    let ptr = vec.as_ptr();
+    let map = StaticMap::<Ordering, _>::try_from_vec(vec).unwrap();
+    assert_eq!(map.as_ptr(), ptr);
+    assert_eq!(*map, StaticMap([1, 2, 3]));
+    assert_eq!(
+        StaticMap::<Ordering, i32>::try_from_vec(vec![1, 2]),
+        Err(vec![1, 2])
+    );
+    let map: Box<StaticMap<bool, _>> = vec![4, 5].try_into().unwrap();
+    assert_eq!(map[true], 5);
+    let res: Result<Box<StaticMap<bool, _>>, _> = vec![4, 5, 6].into_boxed_slice().try_into();
+    assert_eq!(res, Err(vec![4, 5, 6].into_boxed_slice()));
+    let map = BoxedStaticMap::<bool, _>::try_from(vec![7, 8]).unwrap();
+    assert_eq!(map.into_box(), Box::new(StaticMap([7, 8])));
+}
+
+#[test]
+fn panic_drops_initialized() {
+    let rc = Rc::new(());
+    let calls = Cell::new(0);
+    let res = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        StaticMap::<Ordering, _>::boxed_from_fn(|o| {
+            calls.set(calls.get() + 1);
+            if o == Ordering::Greater {
+                panic!();
+            }
+            rc.clone()
+        })
+    }));
+    assert!(res.is_err());
+    assert_eq!(calls.get(), 3);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn api() {
+    let mut map: BoxedStaticMap<Ordering, i32> = [(Ordering::Less, 1)].into_iter().collect();
+    map[&Ordering::Greater] = 3;
+    map.extend([(Ordering::Equal, 2)]);
+    assert_eq!(map, BoxedStaticMap::from_fn(|o: Ordering| o as i32 + 2));
+    for (_, v) in &mut map {
+        *v *= 2;
+    }
+    assert_eq!((&map).into_iter().map(|(_, v)| *v).sum::<i32>(), 12);
+    assert_eq!(format!("{:?}", map), "{Less: 2, Equal: 4, Greater: 6}");
+    let map = BoxedStaticMap::from(StaticMap([1, 2]));
+    assert_eq!(map[false], 1);
+    let boxed: Box<StaticMap<bool, i32>> = map.into();
+    assert_eq!(*boxed, StaticMap([1, 2]));
+}
@@ -0,0 +1,104 @@
+use {
+    linearize::{Incomplete, StaticMap, StaticMapBuilder},
+    std::{cell::Cell, cmp::Ordering, rc::Rc},
+};
+
+#[test]
+fn complete() {
+    let mut builder = StaticMapBuilder::new();
+    assert!(builder.is_empty());
+    builder.set(Ordering::Greater, 1);
+    builder.set(Ordering::Less, -1);
+    assert!(!builder.is_complete());
+    builder.set(Ordering::Equal, 0);
+    assert!(builder.is_complete());
+    assert_eq!(builder.len(), 3);
+    assert_eq!(
+        builder.finish(),
+        Ok(StaticMap::from_fn(|o: Ordering| o as i8))
+    );
+}
+
+#[test]
+fn duplicates() {
+    let mut builder = StaticMapBuilder::new();
+    assert_eq!(builder.set(true, 1), None);
+    assert_eq!(builder.set(true, 2), Some(1));
+    assert_eq!(builder.set_if_absent(true, 3), Err(3));
+    assert_eq!(builder.get(&true), Some(&2));
+    assert_eq!(builder.len(), 1);
+    assert_eq!(builder.set_if_absent(false, 4), Ok(&mut 4));
+    assert_eq!(builder.len(), 2);
+    assert_eq!(builder.remove(&false), Some(4));
+    assert_eq!(builder.remove(&false), None);
+    assert_eq!(builder.len(), 1);
+}
+
+#[test]
+fn missing_keys() {
+    let mut builder = StaticMapBuilder::<u8, ()>::new();
+    for i in 0..=255u8 {
+        if i % 100 != 0 {
+            builder.set(i, ());
+        }
+    }
+    assert_eq!(builder.missing_keys().collect::<Vec<_>>(), [0, 100, 200]);
+    assert_eq!(builder.missing_keys().next_back(), Some(200));
+    let err = builder.finish().unwrap_err();
+    assert!(err.is_missing(&100));
+    assert!(!err.is_missing(&101));
+    assert_eq!(err.missing().values().filter(|m| **m).count(), 3);
+    assert_eq!(err.to_string(), "Missing keys: [0, 100, 200]");
+}
+
+#[test]
+fn incomplete_eq() {
+    let a = StaticMapBuilder::<bool, u8>::new().finish().unwrap_err();
+    let mut b = StaticMapBuilder::new();
+    b.set(true, 1u8);
+    let b: Incomplete<bool> = b.finish().unwrap_err();
+    assert_ne!(a, b);
+    assert_eq!(a, a.clone());
+}
+
+#[test]
+fn drop_partial() {
+    let drops = Rc::new(Cell::new(0));
+    struct D(Rc<Cell<usize>>);
+    impl Drop for D {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+    let mut builder = StaticMapBuilder::new();
+    builder.set(Ordering::Less, D(drops.clone()));
+    builder.set(Ordering::Greater, D(drops.clone()));
+    assert!(builder.finish().is_err());
+    assert_eq!(drops.get(), 2);
+
+    let mut builder = StaticMapBuilder::new();
+    builder.set(Ordering::Less, D(drops.clone()));
+    drop(builder);
+    assert_eq!(drops.get(), 3);
+
+    let mut builder = StaticMapBuilder::new();
+    builder.extend([
+        (Ordering::Less, D(drops.clone())),
+        (Ordering::Equal, D(drops.clone())),
+        (Ordering::Greater, D(drops.clone())),
+    ]);
+    let map = builder.finish().ok().unwrap();
+    assert_eq!(drops.get(), 3);
+    drop(map);
+    assert_eq!(drops.get(), 6);
+}
+
+#[test]
+fn from_map() {
+    let map = StaticMap::from_fn(|b: bool| b as u8);
+    let mut builder = StaticMapBuilder::from(map);
+    assert!(builder.is_complete());
+    builder.take();
+    assert!(builder.is_empty());
+    assert_eq!(format!("{builder:?}"), "{}");
+}
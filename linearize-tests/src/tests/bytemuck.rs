@@ -50,3 +50,40 @@ macro_rules! assert_not_forwards {
 assert_not_forwards!((AnyBitPattern), (NoUninit + Zeroable + Copy));
 assert_not_forwards!((NoUninit), (AnyBitPattern + Zeroable + Copy));
 assert_not_forwards!((Zeroable), (NoUninit + Copy));
+
+const _: () = {
+    #[allow(unconditional_recursion)]
+    fn _forward<T: Zeroable>() {
+        _forward::<linearize::StaticMap<(), T>>();
+    }
+};
+
+#[derive(linearize::Linearize, Copy, Clone, Debug, PartialEq)]
+#[linearize(bytemuck)]
+#[repr(u8)]
+enum Key {
+    A,
+    B,
+    C,
+}
+
+#[derive(linearize::Linearize, Copy, Clone, Debug, PartialEq)]
+#[linearize(bytemuck)]
+#[repr(i16)]
+enum Signed {
+    X,
+    Y,
+}
+
+#[test]
+fn checked_bit_pattern() {
+    use bytemuck::{checked, CheckedBitPattern};
+    assert!(Key::is_valid_bit_pattern(&2));
+    assert!(!Key::is_valid_bit_pattern(&3));
+    let keys: &[Key] = checked::try_cast_slice(&[0u8, 2, 1]).unwrap();
+    assert_eq!(keys, [Key::A, Key::C, Key::B]);
+    assert!(checked::try_cast_slice::<u8, Key>(&[0, 3]).is_err());
+    assert_eq!(checked::try_cast::<i16, Signed>(1), Ok(Signed::Y));
+    assert!(checked::try_cast::<i16, Signed>(-1).is_err());
+    assert!(checked::try_cast::<i16, Signed>(2).is_err());
+}
@@ -0,0 +1,97 @@
+use {
+    linearize::{static_copy_map, StaticCounter},
+    std::cmp::Ordering::{self, *},
+};
+
+fn counter(less: u64, equal: u64, greater: u64) -> StaticCounter<Ordering> {
+    StaticCounter::from_counts(static_copy_map! {
+        Less => less,
+        Equal => equal,
+        Greater => greater,
+    })
+}
+
+#[test]
+fn add() {
+    let mut c = StaticCounter::new();
+    assert!(c.is_empty());
+    c.add(Less);
+    c.add_n(Greater, 3);
+    c.extend([Less, Less]);
+    c.extend(&[Equal]);
+    assert_eq!(c, counter(3, 1, 3));
+    assert_eq!(c.total(), 7);
+    assert_eq!(c.get(&Less), 3);
+    c.remove_n(&Less, 5);
+    assert_eq!(c[Less], 0);
+    c[Equal] += 1;
+    assert_eq!(c, counter(0, 2, 3));
+    c.clear();
+    assert!(c.is_empty());
+}
+
+#[test]
+fn from_iter() {
+    let c: StaticCounter<_> = [Less, Greater, Less].into_iter().collect();
+    assert_eq!(c, counter(2, 0, 1));
+    let c: StaticCounter<_> = [Less, Greater, Less].iter().collect();
+    assert_eq!(c, counter(2, 0, 1));
+}
+
+#[test]
+fn most_common() {
+    let c = counter(1, 5, 1);
+    let mc: Vec<_> = c.most_common(3).collect();
+    assert_eq!(mc, [(Equal, 5), (Less, 1), (Greater, 1)]);
+    assert_eq!(c.most_common(1).len(), 1);
+    assert_eq!(c.most_common(10).len(), 3);
+    assert_eq!(c.most_common(0).next(), None);
+    let c: StaticCounter<u8> = [7, 7, 200].into_iter().collect();
+    let mc: Vec<_> = c.most_common(3).collect();
+    assert_eq!(mc, [(7, 2), (200, 1), (0, 0)]);
+    let c = counter(2, 1, 2);
+    let mc: Vec<_> = c.most_common(3).collect();
+    assert_eq!(mc, [(Less, 2), (Greater, 2), (Equal, 1)]);
+}
+
+#[test]
+fn multiset_ops() {
+    let a = counter(1, 5, 0);
+    let b = counter(3, 2, 1);
+    assert_eq!(a.union(&b), counter(3, 5, 1));
+    assert_eq!(a | b, counter(3, 5, 1));
+    assert_eq!(a.intersection(&b), counter(1, 2, 0));
+    assert_eq!(a & b, counter(1, 2, 0));
+    assert_eq!(a.saturating_sub(&b), counter(0, 3, 0));
+    assert_eq!(a - b, counter(0, 3, 0));
+    assert_eq!(a + b, counter(4, 7, 1));
+    let mut c = a;
+    c += b;
+    c -= a;
+    assert_eq!(c, b);
+    c |= a;
+    c &= b;
+    assert_eq!(c, b);
+}
+
+#[test]
+fn frequencies() {
+    let f = counter(1, 3, 0).frequencies();
+    assert_eq!(f[Less], 0.25);
+    assert_eq!(f[Equal], 0.75);
+    assert_eq!(f[Greater], 0.0);
+    let f = counter(0, 0, 0).frequencies();
+    assert!(f.values().all(|&v| v == 0.0));
+}
+
+#[test]
+fn fmt() {
+    let c = counter(1, 0, 2);
+    assert_eq!(c.to_string(), "{Greater: 2, Less: 1}");
+    assert_eq!(format!("{c:?}"), "{Less: 1, Equal: 0, Greater: 2}");
+    assert_eq!(counter(0, 0, 0).to_string(), "{}");
+    assert_eq!(
+        counter(2, 1, 2).to_string(),
+        "{Less: 2, Greater: 2, Equal: 1}"
+    );
+}
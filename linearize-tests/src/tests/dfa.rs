@@ -0,0 +1,240 @@
+use linearize::{Dfa, Linearize, StaticMap};
+
+#[derive(Linearize, Copy, Clone, Debug, PartialEq)]
+enum State {
+    Idle,
+    Connecting,
+    Connected,
+    Error,
+    Unused,
+}
+
+#[derive(Linearize, Copy, Clone, Debug, PartialEq)]
+enum Event {
+    Connect,
+    Ack,
+    Fail,
+}
+
+fn protocol() -> Dfa<State, Event> {
+    use {Event::*, State::*};
+    Dfa::from_fn(
+        Idle,
+        |s, e| match (s, e) {
+            (Idle, Connect) => Connecting,
+            (Connecting, Ack) => Connected,
+            (Connected, Connect) => Connected,
+            (Unused, _) => Unused,
+            (_, Fail) => Error,
+            (s, _) => s,
+        },
+        |s| s == Connected,
+    )
+}
+
+#[test]
+fn run() {
+    let dfa = protocol();
+    assert_eq!(dfa.start(), State::Idle);
+    assert_eq!(dfa.step(&State::Idle, &Event::Connect), State::Connecting);
+    assert_eq!(dfa.run([Event::Connect, Event::Ack]), State::Connected);
+    assert_eq!(dfa.run_from(&State::Connected, [Event::Fail]), State::Error);
+    assert!(dfa.accepts([Event::Connect, Event::Ack, Event::Connect]));
+    assert!(!dfa.accepts([Event::Connect]));
+    assert!(!dfa.accepts([]));
+}
+
+#[test]
+fn modify() {
+    let mut dfa = protocol();
+    dfa.set_accepting(&State::Error, true);
+    assert!(dfa.accepts([Event::Fail]));
+    dfa.set_transition(&State::Error, &Event::Ack, &State::Idle);
+    assert_eq!(dfa.run([Event::Fail, Event::Ack]), State::Idle);
+    dfa.set_start(&State::Connected);
+    assert!(dfa.accepts([]));
+    assert!(dfa.accepting()[State::Connected]);
+}
+
+#[test]
+fn new() {
+    let transitions = StaticMap::from_fn(|s: bool| StaticMap::from_fn(|e: bool| s ^ e));
+    let accepting = StaticMap::from_fn(|s: bool| s);
+    let dfa = Dfa::new(false, transitions, accepting);
+    assert!(dfa.accepts([true, false, true, true]));
+    assert!(!dfa.accepts([true, true]));
+    assert_eq!(dfa, Dfa::from_fn(false, |s, e| s ^ e, |s| s));
+}
+
+#[test]
+fn reachability() {
+    let mut dfa = protocol();
+    let reachable = dfa.reachable_states();
+    assert!(reachable[State::Error]);
+    assert!(!reachable[State::Unused]);
+    dfa.set_accepting(&State::Unused, true);
+    dfa.set_transition(&State::Unused, &Event::Ack, &State::Connected);
+    dfa.prune_unreachable();
+    assert!(!dfa.is_accepting(&State::Unused));
+    assert_eq!(dfa.step(&State::Unused, &Event::Ack), State::Unused);
+    assert_eq!(dfa.step(&State::Connecting, &Event::Ack), State::Connected);
+}
+
+#[test]
+fn minimize() {
+    // Counts events modulo 6 and accepts multiples of 3.
+    let dfa = Dfa::from_fn(0u8, |s, _: bool| (s % 6 + 1) % 6, |s| s % 3 == 0);
+    let classes = dfa.equivalence_classes();
+    assert_eq!(classes[0], Some(0));
+    assert_eq!(classes[1], Some(1));
+    assert_eq!(classes[2], Some(2));
+    assert_eq!(classes[3], Some(0));
+    assert_eq!(classes[4], Some(1));
+    assert_eq!(classes[5], Some(2));
+    assert_eq!(classes[6], None);
+    let min = dfa.minimize();
+    assert_eq!(min.reachable_states().values().filter(|r| **r).count(), 3);
+    for n in 0..20 {
+        let events = vec![true; n];
+        assert_eq!(min.accepts(events.clone()), dfa.accepts(events));
+    }
+}
+
+#[test]
+fn minimize_sink() {
+    let dfa = protocol();
+    let classes = dfa.equivalence_classes();
+    assert_eq!(classes[State::Unused], None);
+    assert_eq!(classes.values().flatten().max(), Some(&3), "{classes:?}",);
+    // Idle and Error are distinguishable because Idle can still reach Connected.
+    assert_ne!(classes[State::Idle], classes[State::Error]);
+}
+
+#[test]
+fn minimize_merge() {
+    // Two copies of the same two-state automaton.
+    let dfa = Dfa::from_fn(
+        0u8,
+        |s, e: bool| match (s, e) {
+            (0, true) => 3,
+            (3, true) => 0,
+            (0 | 3, false) => s,
+            _ => s,
+        },
+        |s| s == 0 || s == 2,
+    );
+    let classes = dfa.equivalence_classes();
+    assert_eq!(classes[0], Some(0));
+    assert_eq!(classes[3], Some(1));
+    let min = dfa.minimize();
+    assert_eq!(min.start(), 0);
+}
+
+#[test]
+fn product() {
+    #[derive(Linearize, Copy, Clone, Debug, PartialEq)]
+    struct Pair(bool, bool);
+
+    // Parity of `true` events.
+    let a = Dfa::from_fn(false, |s, e: bool| s ^ e, |s| s);
+    // Whether the last event was `true`.
+    let b = Dfa::from_fn(false, |_, e| e, |s| s);
+    let and = a.product::<bool, Pair>(&b, |a, b| a && b);
+    let or = a.product::<bool, Pair>(&b, |a, b| a || b);
+    assert_eq!(and.start(), Pair(false, false));
+    assert_eq!(and.run([true, false]), Pair(true, false));
+    assert!(and.accepts([true]));
+    assert!(!and.accepts([true, true]));
+    assert!(or.accepts([true, true]));
+    assert!(!or.accepts([true, true, false]));
+}
+
+#[test]
+fn dot() {
+    #[derive(Linearize, Copy, Clone, Debug, PartialEq)]
+    enum Quote {
+        A,
+        B,
+    }
+
+    #[derive(Linearize, Copy, Clone, Debug, PartialEq)]
+    enum E {
+        X(Quote),
+    }
+
+    let dfa = Dfa::from_fn(Quote::A, |_, _: E| Quote::A, |_| false);
+    assert_eq!(
+        dfa.dot().to_string(),
+        "digraph {\n    start [shape=point];\n    start -> 0;\n    0 [label=\"A\", shape=circle];\n    0 -> 0 [label=\"X(A), X(B)\"];\n}\n",
+    );
+    let dfa = Dfa::from_fn(false, |_, _: char_like::C| true, |_| true);
+    assert!(dfa.dot().to_string().contains("[label=\"\\\"q\\\\\\\"\"]"));
+}
+
+mod char_like {
+    use linearize::Linearize;
+
+    #[derive(Linearize, Copy, Clone, PartialEq)]
+    pub struct C;
+
+    impl std::fmt::Debug for C {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("\"q\\\"")
+        }
+    }
+}
+
+#[test]
+fn debug() {
+    let dfa = Dfa::from_fn(false, |s, _: ()| !s, |s| s);
+    assert_eq!(
+        format!("{dfa:?}"),
+        "Dfa { start: false, transitions: {false: {(): true}, true: {(): false}}, accepting: {false: false, true: true} }",
+    );
+}
+
+#[test]
+fn minimize_random() {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[derive(Linearize, Copy, Clone, Debug, PartialEq)]
+    struct S(bool, bool, bool);
+
+    // Two states are equivalent iff they agree on all words of length < 8.
+    fn signature(dfa: &Dfa<S, bool>, s: &S) -> Vec<bool> {
+        let mut res = vec![];
+        for bits in 0..1u32 << 8 {
+            for len in 0..8 {
+                let state = dfa.run_from(s, (0..len).map(|i| bits & (1 << i) != 0));
+                res.push(dfa.is_accepting(&state));
+            }
+        }
+        res
+    }
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..200 {
+        let transitions = StaticMap::from_fn(|_: S| {
+            StaticMap::from_fn(|_: bool| S(rng.gen(), rng.gen(), rng.gen()))
+        });
+        let accepting = StaticMap::from_fn(|_: S| rng.gen());
+        let dfa = Dfa::new(S(false, false, false), transitions, accepting);
+        let classes = dfa.equivalence_classes();
+        let reachable = dfa.reachable_states();
+        let signatures = StaticMap::from_fn(|s: S| signature(&dfa, &s));
+        for (a, ca) in classes.iter() {
+            assert_eq!(ca.is_some(), reachable[&a]);
+            for (b, cb) in classes.iter() {
+                if let (Some(ca), Some(cb)) = (ca, cb) {
+                    assert_eq!(ca == cb, signatures[&a] == signatures[&b]);
+                }
+            }
+        }
+        let min = dfa.minimize();
+        assert_eq!(
+            min.reachable_states().values().filter(|r| **r).count(),
+            classes.values().flatten().max().unwrap() + 1,
+        );
+        assert_eq!(signature(&min, &min.start()), signatures[dfa.start()]);
+    }
+}
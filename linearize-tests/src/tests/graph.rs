@@ -0,0 +1,137 @@
+use linearize::{Linearize, StaticGraph};
+
+#[derive(Linearize, Copy, Clone, Debug, PartialEq, Eq)]
+enum N {
+    A,
+    B,
+    C,
+    D,
+    E,
+}
+
+use N::*;
+
+fn graph(edges: &[(N, N)]) -> StaticGraph<N> {
+    edges.iter().copied().collect()
+}
+
+#[test]
+fn edges() {
+    let mut g = StaticGraph::new();
+    assert!(g.add_edge(&A, &B));
+    assert!(!g.add_edge(&A, &B));
+    assert!(g.add_edge(&C, &A));
+    assert!(g.add_edge(&A, &A));
+    assert!(g.contains_edge(&A, &B));
+    assert!(!g.contains_edge(&B, &A));
+    assert_eq!(g.edge_count(), 3);
+    assert!(g.remove_edge(&A, &B));
+    assert!(!g.remove_edge(&A, &B));
+    assert_eq!(g.edge_count(), 2);
+    g.clear();
+    assert_eq!(g, StaticGraph::new());
+}
+
+#[test]
+fn neighbors() {
+    let g = graph(&[(A, B), (A, D), (C, D), (D, D), (E, A)]);
+    assert_eq!(g.successors(&A).collect::<Vec<_>>(), [B, D]);
+    assert_eq!(g.successors(&A).len(), 2);
+    assert_eq!(g.successors(&B).next(), None);
+    assert_eq!(g.predecessors(&D).collect::<Vec<_>>(), [A, C, D]);
+    assert_eq!(g.predecessors(&E).next(), None);
+    let r = g.reverse();
+    assert_eq!(r.successors(&D).collect::<Vec<_>>(), [A, C, D]);
+    assert_eq!(
+        format!("{g:?}"),
+        "{A: {B, D}, B: {}, C: {D}, D: {D}, E: {A}}"
+    );
+}
+
+#[test]
+fn large() {
+    let mut g = StaticGraph::<u8>::new();
+    g.add_edge(&0, &255);
+    g.add_edge(&0, &64);
+    g.add_edge(&0, &63);
+    g.add_edge(&200, &0);
+    assert_eq!(g.successors(&0).collect::<Vec<_>>(), [63, 64, 255]);
+    assert_eq!(g.successors(&0).len(), 3);
+    assert_eq!(g.predecessors(&0).collect::<Vec<_>>(), [200]);
+    assert!(g.is_reachable(&200, &255));
+    assert!(!g.is_reachable(&255, &200));
+    assert_eq!(StaticGraph::<u8>::complete().edge_count(), 256 * 256);
+    let closure = g.transitive_closure();
+    assert!(closure.contains_edge(&200, &64));
+    assert_eq!(closure.edge_count(), 7);
+}
+
+#[test]
+fn transitive_closure() {
+    let g = graph(&[(A, B), (B, C), (C, A), (D, E)]);
+    let c = g.transitive_closure();
+    for x in [A, B, C] {
+        for y in [A, B, C] {
+            assert!(c.contains_edge(&x, &y));
+        }
+        assert!(!c.contains_edge(&x, &D));
+    }
+    assert!(c.contains_edge(&D, &E));
+    assert!(!c.contains_edge(&D, &D));
+    assert_eq!(c.edge_count(), 10);
+}
+
+#[test]
+fn reachability() {
+    let g = graph(&[(A, B), (B, C), (D, C)]);
+    let r = g.reachable_from(&A);
+    assert!(r[A] && r[B] && r[C]);
+    assert!(!r[D] && !r[E]);
+    assert!(g.is_reachable(&E, &E));
+    assert!(!g.is_reachable(&C, &A));
+}
+
+#[test]
+fn topological_sort() {
+    let g = graph(&[(E, A), (A, C), (B, C), (C, D)]);
+    let ranks = g.topological_sort().unwrap();
+    assert_eq!(ranks[B], 0);
+    assert_eq!(ranks[E], 1);
+    assert_eq!(ranks[A], 2);
+    assert_eq!(ranks[C], 3);
+    assert_eq!(ranks[D], 4);
+    let empty = StaticGraph::<N>::new().topological_sort().unwrap();
+    assert_eq!(empty[D], 3);
+}
+
+#[test]
+fn topological_sort_smallest_first() {
+    let ranks = graph(&[(A, B)]).topological_sort().unwrap();
+    assert_eq!(ranks.0, [0, 1, 2, 3, 4]);
+    let g: StaticGraph<u8> = [(0, 1)].into_iter().collect();
+    let ranks = g.topological_sort().unwrap();
+    assert!(ranks.iter().all(|(k, &r)| r == k as usize));
+}
+
+#[test]
+fn topological_sort_cycle() {
+    let g = graph(&[(A, B), (B, D), (D, C), (C, B), (A, E)]);
+    let err = g.topological_sort().unwrap_err();
+    assert_eq!(err.cycle(), [B, D, C]);
+    assert_eq!(err.to_string(), "Graph contains a cycle: [B, D, C]");
+    let err = graph(&[(C, C)]).topological_sort().unwrap_err();
+    assert_eq!(err.into_cycle(), [C]);
+}
+
+#[test]
+fn strongly_connected_components() {
+    let g = graph(&[(E, A), (A, B), (B, A), (B, C), (C, D), (D, C)]);
+    let scc = g.strongly_connected_components();
+    assert_eq!(scc[E], 0);
+    assert_eq!(scc[A], 1);
+    assert_eq!(scc[B], 1);
+    assert_eq!(scc[C], 2);
+    assert_eq!(scc[D], 2);
+    let scc = StaticGraph::<N>::new().strongly_connected_components();
+    assert_eq!(scc.values().copied().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+}
@@ -0,0 +1,71 @@
+use {
+    linearize::{LazyStaticMap, StaticMap, SyncLazyStaticMap},
+    std::{
+        cell::Cell,
+        cmp::Ordering,
+        sync::atomic::{AtomicUsize, Ordering::Relaxed},
+        thread,
+    },
+};
+
+#[test]
+fn on_demand() {
+    let calls = Cell::new(0);
+    let map = LazyStaticMap::new(|o: Ordering| {
+        calls.set(calls.get() + 1);
+        o as i32
+    });
+    assert_eq!(map.iter_initialized().count(), 0);
+    assert!(!map.is_init(&Ordering::Less));
+    assert_eq!(*map.get(&Ordering::Less), -1);
+    assert_eq!(*map.get(&Ordering::Less), -1);
+    assert_eq!(*map.get(&Ordering::Greater), 1);
+    assert_eq!(calls.get(), 2);
+    assert_eq!(map.get_if_init(&Ordering::Equal), None);
+    assert_eq!(map.get_if_init(&Ordering::Greater), Some(&1));
+    assert_eq!(
+        map.iter_initialized().collect::<Vec<_>>(),
+        [(Ordering::Less, &-1), (Ordering::Greater, &1)]
+    );
+    assert_eq!(format!("{:?}", map), "{Less: -1, Greater: 1}");
+    assert_eq!(map.into_static_map(), StaticMap([-1, 0, 1]));
+    assert_eq!(calls.get(), 3);
+}
+
+#[test]
+fn force_and_reset() {
+    let calls = Cell::new(0);
+    let mut map = LazyStaticMap::new(|b: bool| {
+        calls.set(calls.get() + 1);
+        b
+    });
+    map.force_all();
+    assert_eq!(calls.get(), 2);
+    assert_eq!(map.iter_initialized().count(), 2);
+    map.reset();
+    assert_eq!(map.get_if_init(&true), None);
+    assert_eq!(map.into_static_map(), StaticMap([false, true]));
+    assert_eq!(calls.get(), 4);
+}
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+static SQUARES: SyncLazyStaticMap<u8, u32> = SyncLazyStaticMap::new(|n| {
+    CALLS.fetch_add(1, Relaxed);
+    n as u32 * n as u32
+});
+
+#[test]
+fn sync_static() {
+    thread::scope(|s| {
+        for _ in 0..8 {
+            s.spawn(|| {
+                for n in 0..=255 {
+                    assert_eq!(*SQUARES.get(&n), n as u32 * n as u32);
+                }
+            });
+        }
+    });
+    assert_eq!(CALLS.load(Relaxed), 256);
+    assert_eq!(SQUARES.iter_initialized().count(), 256);
+}
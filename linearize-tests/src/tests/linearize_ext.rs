@@ -20,3 +20,65 @@ fn linearized() {
     assert_eq!(false.linearized().get(), 0);
     assert_eq!(true.linearized().get(), 1);
 }
+
+#[test]
+fn first_last() {
+    assert_eq!(bool::first(), Some(false));
+    assert_eq!(bool::last(), Some(true));
+    assert_eq!(u8::first(), Some(0));
+    assert_eq!(u8::last(), Some(255));
+    assert_eq!(<()>::first(), Some(()));
+    assert_eq!(<()>::last(), Some(()));
+}
+
+#[test]
+fn first_last_empty() {
+    #[derive(linearize::Linearize, Debug, PartialEq)]
+    enum Empty {}
+    assert_eq!(Empty::first(), None);
+    assert_eq!(Empty::last(), None);
+}
+
+#[test]
+fn succ_pred() {
+    assert_eq!(0u8.succ(), Some(1));
+    assert_eq!(255u8.succ(), None);
+    assert_eq!(0u8.pred(), None);
+    assert_eq!(255u8.pred(), Some(254));
+    assert_eq!(255u8.wrapping_succ(), 0);
+    assert_eq!(0u8.wrapping_pred(), 255);
+    assert_eq!(().wrapping_succ(), ());
+    assert_eq!(().wrapping_pred(), ());
+}
+
+#[test]
+fn offset() {
+    assert_eq!(10u8.checked_offset(0), Some(10));
+    assert_eq!(10u8.checked_offset(245), Some(255));
+    assert_eq!(10u8.checked_offset(246), None);
+    assert_eq!(10u8.checked_offset(-10), Some(0));
+    assert_eq!(10u8.checked_offset(-11), None);
+    assert_eq!(10u8.checked_offset(isize::MAX), None);
+    assert_eq!(10u8.checked_offset(isize::MIN), None);
+    for offset in -600isize..600 {
+        let expected = (10 + offset).rem_euclid(256) as u8;
+        assert_eq!(10u8.wrapping_offset(offset), expected);
+    }
+    assert_eq!(
+        10u8.wrapping_offset(isize::MAX),
+        (10 + (isize::MAX % 256)) as u8
+    );
+    assert_eq!(10u8.wrapping_offset(isize::MIN), 10);
+}
+
+#[test]
+fn distance() {
+    assert_eq!(3u8.distance(&3), 0);
+    assert_eq!(3u8.distance(&255), 252);
+    assert_eq!(255u8.distance(&3), -252);
+    for a in [0u8, 7, 255] {
+        for b in [0u8, 7, 255] {
+            assert_eq!(a.checked_offset(a.distance(&b)), Some(b));
+        }
+    }
+}
@@ -24,3 +24,22 @@ fn linearized() {
         Ordering::Greater
     );
 }
+
+#[test]
+fn navigation() {
+    use linearize::Linearized;
+    let less = Ordering::Less.linearized();
+    let equal = Ordering::Equal.linearized();
+    let greater = Ordering::Greater.linearized();
+    assert_eq!(Linearized::<Ordering>::first(), Some(less));
+    assert_eq!(Linearized::<Ordering>::last(), Some(greater));
+    assert_eq!(less.succ(), Some(equal));
+    assert_eq!(greater.succ(), None);
+    assert_eq!(less.pred(), None);
+    assert_eq!(greater.wrapping_succ(), less);
+    assert_eq!(less.wrapping_pred(), greater);
+    assert_eq!(less.checked_offset(2), Some(greater));
+    assert_eq!(greater.wrapping_offset(-4), equal);
+    assert_eq!(less.distance(greater), 2);
+    assert_eq!(greater.distance(less), -2);
+}
@@ -791,3 +791,21 @@ fn try_from_iterator() {
     assert_eq!(map[false], 1);
     assert_eq!(map[true], 2);
 }
+
+#[test]
+fn transpose() {
+    let map = StaticMap::from_fn(|a: bool| StaticMap::from_fn(|b: Ordering| (a, b)));
+    let map = map.transpose();
+    for (b, row) in &map {
+        for (a, v) in row {
+            assert_eq!(*v, (a, b));
+        }
+    }
+}
+
+#[test]
+fn transpose_drop() {
+    let map = StaticMap::from_fn(|a: bool| StaticMap::from_fn(|b: Ordering| Box::new((a, b))));
+    let map = map.transpose();
+    assert_eq!(*map[Ordering::Less][true], (true, Ordering::Less));
+}
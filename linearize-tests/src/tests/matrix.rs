@@ -0,0 +1,105 @@
+use {
+    linearize::{Linearize, StaticMap, StaticMatrix},
+    std::cmp::Ordering,
+};
+
+#[derive(Linearize, Copy, Clone, Debug, PartialEq)]
+enum K {
+    A,
+    B,
+    C,
+}
+
+#[test]
+fn index() {
+    let mut m = StaticMatrix::from_fn(|r: bool, c: K| (r, c));
+    assert_eq!(m[(true, K::B)], (true, K::B));
+    assert_eq!(m[(&false, &K::C)], (false, K::C));
+    m[(true, K::A)] = (false, K::A);
+    assert_eq!(*m.get(&true, &K::A), (false, K::A));
+    *m.get_mut(&true, &K::A) = (true, K::C);
+    assert_eq!(m.rows()[true][K::A], (true, K::C));
+}
+
+#[test]
+fn rows() {
+    let mut m = StaticMatrix::from_fn(|r: bool, c: K| r as u8 * 10 + c as u8);
+    assert_eq!(m.row(&true), &StaticMap::from_fn(|c: K| 10 + c as u8));
+    m.row_mut(&false)[K::C] = 99;
+    assert_eq!(m[(false, K::C)], 99);
+    let rows = m.clone().into_rows();
+    assert_eq!(StaticMatrix::from_rows(rows), m);
+}
+
+#[test]
+fn col() {
+    let mut m = StaticMatrix::from_fn(|r: K, c: bool| r as u8 * 10 + c as u8);
+    let col = m.col(&true);
+    assert!(col.key());
+    assert_eq!(col[K::B], 11);
+    assert_eq!(col.get(&K::C), &21);
+    let entries: Vec<_> = col.iter().collect();
+    assert_eq!(entries, [(K::A, &1), (K::B, &11), (K::C, &21)]);
+    assert_eq!(col.iter().next_back(), Some((K::C, &21)));
+    assert_eq!(col.iter().len(), 3);
+    assert_eq!(
+        col.to_static_map(),
+        StaticMap::from_fn(|r: K| r as u8 * 10 + 1)
+    );
+    assert_eq!(format!("{col:?}"), "{A: 1, B: 11, C: 21}");
+
+    let mut col = m.col_mut(&false);
+    col[K::A] = 5;
+    *col.get_mut(&K::B) += 1;
+    for (_, v) in col.iter_mut() {
+        *v += 100;
+    }
+    assert_eq!(col[&K::C], 120);
+    assert_eq!(m[(K::A, false)], 105);
+    assert_eq!(m[(K::B, false)], 111);
+    assert_eq!(m[(K::B, true)], 11);
+}
+
+#[test]
+fn transpose() {
+    let m = StaticMatrix::from_fn(|r: bool, c: K| (r, c));
+    let t: StaticMatrix<K, bool, _> = m.clone().transpose();
+    for r in [false, true] {
+        for c in [K::A, K::B, K::C] {
+            assert_eq!(t[(c, r)], m[(r, c)]);
+        }
+    }
+    assert_eq!(t.transpose(), m);
+}
+
+#[test]
+fn identity() {
+    let m = StaticMatrix::<Ordering, Ordering, i32>::identity();
+    assert_eq!(m[(Ordering::Less, Ordering::Less)], 1);
+    assert_eq!(m[(Ordering::Less, Ordering::Greater)], 0);
+    let a = StaticMatrix::from_fn(|r: Ordering, c: Ordering| r as i32 * 3 + c as i32);
+    assert_eq!(&a * &m, a);
+    assert_eq!(&m * &a, a);
+}
+
+#[test]
+fn mul() {
+    // [1 2 3]   [1 0]   [ 4  2]
+    // [4 5 6] * [0 1] = [10  5]
+    //           [1 0]
+    let a = StaticMatrix::from_fn(|r: bool, k: K| r as i64 * 3 + k as i64 + 1);
+    let b = StaticMatrix::from_fn(|k: K, c: bool| (k as usize % 2 == c as usize) as i64);
+    let m: StaticMatrix<bool, bool, i64> = a * b;
+    assert_eq!(m[(false, false)], 4);
+    assert_eq!(m[(false, true)], 2);
+    assert_eq!(m[(true, false)], 10);
+    assert_eq!(m[(true, true)], 5);
+}
+
+#[test]
+fn map_values() {
+    let m = StaticMatrix::from_fn(|r: bool, c: bool| r as u8 + c as u8);
+    let m = m.map_values(|v| v * 2);
+    assert_eq!(m[(true, true)], 4);
+    assert_eq!(StaticMatrix::<bool, bool, u8>::default()[(true, false)], 0);
+}
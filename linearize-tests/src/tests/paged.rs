@@ -0,0 +1,107 @@
+use {
+    linearize::{LinearizeExt, PagedStaticMap},
+    std::cmp::Ordering,
+};
+
+#[test]
+fn sparse() {
+    let mut map = PagedStaticMap::<u32, u64>::new();
+    assert_eq!(map[u32::MAX], 0);
+    assert!(!map.is_allocated(&u32::MAX));
+    map[u32::MAX] = 1;
+    map[&0] = 2;
+    assert_eq!(map.set(&1, 3), 0);
+    assert_eq!(map.allocated_pages(), 2);
+    assert!(map.is_allocated(&255));
+    assert!(!map.is_allocated(&256));
+    assert_eq!(map[u32::MAX], 1);
+    assert_eq!(map[0.linearized()], 2);
+    assert_eq!(map[1000], 0);
+    let allocated: Vec<_> = map
+        .iter_allocated()
+        .filter(|(_, v)| **v != 0)
+        .map(|(k, v)| (k, *v))
+        .collect();
+    assert_eq!(allocated, [(0, 2), (1, 3), (u32::MAX, 1)]);
+    assert_eq!(map.iter_allocated().count(), 2 * 256);
+    for (_, v) in map.iter_allocated_mut() {
+        *v += 1;
+    }
+    assert_eq!(map[2], 1);
+    assert_eq!(map[3], 1);
+    assert_eq!(map[1000], 0);
+    map.clear();
+    assert_eq!(map.allocated_pages(), 0);
+    assert_eq!(map[0], 0);
+}
+
+#[test]
+fn iter() {
+    let mut map = PagedStaticMap::<u16, u16>::with_default(7);
+    assert_eq!(map.default_value(), &7);
+    map[300] = 1;
+    let values: Vec<_> = map.iter().map(|(k, v)| (k, *v)).collect();
+    assert_eq!(values.len(), 1 << 16);
+    for (i, (k, v)) in values.into_iter().enumerate() {
+        assert_eq!(k as usize, i);
+        assert_eq!(v, if k == 300 { 1 } else { 7 });
+    }
+    assert_eq!(map.values().len(), 1 << 16);
+    assert_eq!((&map).into_iter().filter(|(_, v)| **v == 1).count(), 1);
+}
+
+#[test]
+fn short_page() {
+    let mut map: PagedStaticMap<Ordering, i32> = [(Ordering::Greater, 1)].into_iter().collect();
+    assert_eq!(map.iter_allocated().count(), 3);
+    map.extend([(Ordering::Less, -1)]);
+    assert_eq!(map.iter().map(|(_, v)| *v).collect::<Vec<_>>(), [-1, 0, 1]);
+}
+
+#[test]
+fn eq() {
+    let mut a = PagedStaticMap::<u16, u8>::new();
+    let mut b = PagedStaticMap::<u16, u8>::new();
+    assert_eq!(a, b);
+    a[10] = 0;
+    assert_eq!(a, b);
+    b[1000] = 1;
+    assert_ne!(a, b);
+    a[1000] = 1;
+    assert_eq!(a, b);
+    let c = PagedStaticMap::<u16, u8>::with_default(1);
+    assert_ne!(a, c);
+    let mut d = PagedStaticMap::<bool, u8>::with_default(1);
+    d[false] = 2;
+    d[true] = 3;
+    let mut e = PagedStaticMap::<bool, u8>::with_default(2);
+    e[true] = 3;
+    assert_eq!(d, e);
+    assert_eq!(d.clone(), e);
+}
+
+#[test]
+fn compact() {
+    let mut map = PagedStaticMap::<u16, u8>::new();
+    map[0] = 1;
+    map[1000] = 1;
+    map[1000] = 0;
+    assert_eq!(map.allocated_pages(), 2);
+    map.compact();
+    assert_eq!(map.allocated_pages(), 1);
+    assert_eq!(map[0], 1);
+}
+
+#[test]
+fn debug() {
+    let mut map = PagedStaticMap::<bool, u8>::new();
+    assert_eq!(
+        format!("{:?}", map),
+        "PagedStaticMap { default: 0, allocated: {} }"
+    );
+    map[true] = 1;
+    assert_eq!(
+        format!("{:?}", map),
+        "PagedStaticMap { default: 0, allocated: {false: 0, true: 1} }"
+    );
+}
@@ -0,0 +1,56 @@
+use {
+    linearize::{StaticMap, StaticMapPatch},
+    std::cmp::Ordering,
+};
+
+#[test]
+fn diff() {
+    let a = StaticMap([1, 2, 3]);
+    let b = StaticMap([1, 5, 4]);
+    assert_eq!(
+        a.diff(&b).collect::<Vec<_>>(),
+        [(Ordering::Equal, &2, &5), (Ordering::Greater, &3, &4)]
+    );
+    assert_eq!(a.diff(&a).count(), 0);
+}
+
+#[test]
+fn apply_and_invert() {
+    let old = StaticMap([1, 2, 3]);
+    let new = StaticMap([0, 2, 4]);
+    let patch = StaticMapPatch::from_diff(&old, &new);
+    assert_eq!(patch.len(), 2);
+    assert!(!patch.contains_key(&Ordering::Equal));
+    assert_eq!(
+        patch.iter().collect::<Vec<_>>(),
+        [(Ordering::Less, &0), (Ordering::Greater, &4)]
+    );
+    let inverse = old.invert(&patch);
+    assert_eq!(
+        inverse,
+        [(Ordering::Less, 1), (Ordering::Greater, 3)]
+            .into_iter()
+            .collect()
+    );
+    let mut map = old.clone();
+    let undo = map.apply(patch.clone());
+    assert_eq!(map, new);
+    assert_eq!(undo, inverse);
+    map.apply(undo);
+    assert_eq!(map, old);
+    assert!(StaticMapPatch::from_diff(&old, &old).is_empty());
+}
+
+#[test]
+fn edit() {
+    let mut patch = StaticMapPatch::<Ordering, i32>::new();
+    assert!(patch.is_empty());
+    assert_eq!(patch.set(&Ordering::Less, 1), None);
+    assert_eq!(patch.set(&Ordering::Less, 2), Some(1));
+    patch.extend([(Ordering::Equal, 3)]);
+    assert_eq!(patch.get(&Ordering::Less), Some(&2));
+    assert_eq!(format!("{:?}", patch), "{Less: 2, Equal: 3}");
+    assert_eq!(patch.remove(&Ordering::Less), Some(2));
+    assert_eq!(patch.changes(), &StaticMap([None, Some(3), None]));
+    assert_eq!(patch.into_changes(), StaticMap([None, Some(3), None]));
+}
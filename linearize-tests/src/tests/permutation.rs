@@ -0,0 +1,177 @@
+use {
+    linearize::{
+        static_copy_map, static_map, Collision, Linearize, LinearizeExt, Permutation, StaticMap,
+    },
+    std::collections::HashSet,
+};
+
+#[derive(Linearize, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+enum E {
+    A,
+    B,
+    C,
+    D,
+}
+
+#[test]
+fn try_invert() {
+    let map = static_copy_map! {
+        E::A => E::C,
+        E::B => E::A,
+        E::C => E::D,
+        E::D => E::B,
+    };
+    let inverse = map.try_invert().unwrap();
+    for (k, v) in &map {
+        assert_eq!(inverse[v], k);
+    }
+}
+
+#[test]
+fn try_invert_different_types() {
+    #[derive(Linearize, Copy, Clone, Debug, PartialEq)]
+    enum F {
+        X,
+        Y,
+    }
+    let map = static_map! {
+        false => F::Y,
+        true => F::X,
+    };
+    let inverse = map.try_invert().unwrap();
+    assert!(inverse[F::X]);
+    assert!(!inverse[F::Y]);
+}
+
+#[test]
+fn try_invert_collision() {
+    let map = static_copy_map! {
+        E::A => E::C,
+        E::B => E::A,
+        E::C => E::A,
+        E::D => E::C,
+    };
+    let err = map.try_invert().unwrap_err();
+    assert_eq!(
+        err,
+        Collision {
+            value: E::A,
+            first: E::B,
+            second: E::C,
+        }
+    );
+    assert_eq!(err.to_string(), "Keys B and C both map to A");
+}
+
+#[test]
+fn identity() {
+    let id = Permutation::<E>::identity();
+    for e in E::variants() {
+        assert_eq!(id.get(&e), e);
+    }
+    assert_eq!(id, Permutation::default());
+    assert!(id.is_even());
+    assert_eq!(id.cycles().count(), 4);
+}
+
+#[test]
+fn from_fn_collision() {
+    let err = Permutation::from_fn(|e: E| match e {
+        E::A => E::B,
+        _ => E::A,
+    })
+    .unwrap_err();
+    assert_eq!(err.value, E::A);
+    assert_eq!(err.first, E::B);
+    assert_eq!(err.second, E::C);
+}
+
+fn rotation() -> Permutation<E> {
+    Permutation::from_fn(|e: E| match e {
+        E::A => E::B,
+        E::B => E::C,
+        E::C => E::D,
+        E::D => E::A,
+    })
+    .unwrap()
+}
+
+#[test]
+fn compose() {
+    let r = rotation();
+    let r2 = r.compose(&r);
+    assert_eq!(r2.get(&E::A), E::C);
+    assert_eq!(r2.get(&E::D), E::B);
+    assert_eq!(r2.compose(&r2), Permutation::identity());
+    assert_eq!(r.compose(&r.inverse()), Permutation::identity());
+    assert_eq!(r.inverse().compose(&r), Permutation::identity());
+}
+
+#[test]
+fn inverse() {
+    let r = rotation().inverse();
+    assert_eq!(r.get(&E::A), E::D);
+    assert_eq!(r.get(&E::B), E::A);
+}
+
+#[test]
+fn cycles() {
+    let r = rotation();
+    let cycles: Vec<Vec<E>> = r.cycles().map(|c| c.collect()).collect();
+    assert_eq!(cycles, [vec![E::A, E::B, E::C, E::D]]);
+    let p = r.compose(&r);
+    let cycles: Vec<Vec<E>> = p.cycles().map(|c| c.collect()).collect();
+    assert_eq!(cycles, [vec![E::A, E::C], vec![E::B, E::D]]);
+}
+
+#[test]
+fn parity() {
+    let r = rotation();
+    assert!(r.is_odd());
+    assert!(r.compose(&r).is_even());
+    let swap = Permutation::from_fn(|e: E| match e {
+        E::A => E::B,
+        E::B => E::A,
+        e => e,
+    })
+    .unwrap();
+    assert!(swap.is_odd());
+}
+
+#[test]
+fn apply() {
+    let map = static_map! {
+        E::A => "a".to_string(),
+        E::B => "b".to_string(),
+        E::C => "c".to_string(),
+        E::D => "d".to_string(),
+    };
+    let map = rotation().apply(map);
+    assert_eq!(map[E::A], "d");
+    assert_eq!(map[E::B], "a");
+    assert_eq!(map[E::C], "b");
+    assert_eq!(map[E::D], "c");
+}
+
+#[test]
+fn to_map() {
+    let map: StaticMap<E, E> = rotation().to_map();
+    assert_eq!(map[E::D], E::A);
+    assert_eq!(Permutation::try_from_map(&map).unwrap(), rotation());
+}
+
+#[test]
+fn all() {
+    let all: Vec<_> = Permutation::<E>::all().collect();
+    assert_eq!(all.len(), 24);
+    assert_eq!(all[0], Permutation::identity());
+    assert_eq!(all.iter().copied().collect::<HashSet<_>>().len(), 24);
+    assert_eq!(all.iter().filter(|p| p.is_even()).count(), 12);
+    assert_eq!(Permutation::<()>::all().count(), 1);
+}
+
+#[test]
+fn debug() {
+    let swap = Permutation::from_fn(|b: bool| !b).unwrap();
+    assert_eq!(format!("{swap:?}"), "{false: true, true: false}");
+}
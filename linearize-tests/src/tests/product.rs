@@ -0,0 +1,86 @@
+use {
+    linearize::{Linearize, LinearizeProduct, StaticMap},
+    std::cmp::Ordering,
+};
+
+#[derive(Linearize, Debug, PartialEq)]
+struct Pos {
+    a: bool,
+    b: Ordering,
+}
+
+#[test]
+fn derive() {
+    fn assert_product<P: LinearizeProduct<First = bool, Second = Ordering>>() {}
+    assert_product::<Pos>();
+
+    #[derive(Linearize)]
+    struct Tuple(bool, Ordering);
+    assert_product::<Tuple>();
+}
+
+#[test]
+fn curry() {
+    let map = StaticMap::from_fn(|p: Pos| (p.a, p.b));
+    let map = map.curry();
+    for (a, row) in &map {
+        for (b, v) in row {
+            assert_eq!(*v, (a, b));
+        }
+    }
+}
+
+#[test]
+fn uncurry() {
+    let map = StaticMap::from_fn(|a: bool| StaticMap::from_fn(|b: Ordering| (a, b)));
+    let map: StaticMap<Pos, _> = map.uncurry();
+    for (p, v) in &map {
+        assert_eq!(*v, (p.a, p.b));
+    }
+}
+
+#[test]
+fn as_curried() {
+    let mut map = StaticMap::from_fn(|p: Pos| p.linearize());
+    assert_eq!(map.as_curried()[true][Ordering::Equal], 4);
+    map.as_curried_mut()[false][Ordering::Greater] = 11;
+    assert_eq!(
+        map[Pos {
+            a: false,
+            b: Ordering::Greater
+        }],
+        11
+    );
+}
+
+#[test]
+fn as_uncurried() {
+    let mut map = StaticMap::from_fn(|a: bool| StaticMap::from_fn(|b: Ordering| (a, b)));
+    assert_eq!(
+        map.as_uncurried::<Pos>()[Pos {
+            a: true,
+            b: Ordering::Less
+        }],
+        (true, Ordering::Less)
+    );
+    map.as_uncurried_mut::<Pos>()[Pos {
+        a: false,
+        b: Ordering::Less,
+    }] = (true, Ordering::Greater);
+    assert_eq!(map[false][Ordering::Less], (true, Ordering::Greater));
+}
+
+#[test]
+fn curry_drop() {
+    let map = StaticMap::from_fn(|p: Pos| Box::new(p.linearize()));
+    let map = map.curry();
+    assert_eq!(*map[true][Ordering::Less], 3);
+    let map: StaticMap<Pos, _> = map.uncurry();
+    assert_eq!(
+        *map[Pos {
+            a: true,
+            b: Ordering::Less
+        }],
+        3
+    );
+}
@@ -0,0 +1,120 @@
+use linearize::{static_copy_map, static_map, Linearize, LinearizeExt, StaticMap};
+
+#[derive(Linearize, Copy, Clone, Debug, PartialEq)]
+#[allow(clippy::enum_variant_names)]
+enum E {
+    A,
+    B,
+    C,
+    D,
+    E,
+}
+
+fn map() -> StaticMap<E, u8> {
+    static_map! {
+        E::A => 0,
+        E::B => 1,
+        E::C => 2,
+        E::D => 3,
+        E::E => 4,
+    }
+}
+
+#[test]
+fn range() {
+    let map = map();
+    assert_eq!(*map.range(E::B..=E::D), [1, 2, 3]);
+    assert_eq!(*map.range(E::B..E::D), [1, 2]);
+    assert_eq!(*map.range(..E::C), [0, 1]);
+    assert_eq!(*map.range(..=E::C), [0, 1, 2]);
+    assert_eq!(*map.range(E::D..), [3, 4]);
+    assert_eq!(*map.range(..), [0, 1, 2, 3, 4]);
+    assert!(map.range(E::D..E::B).is_empty());
+    assert!(map.range(E::C..E::C).is_empty());
+}
+
+#[test]
+fn range_iter() {
+    let map = map();
+    let range = map.range(E::B..=E::D);
+    assert!(range.keys().eq([E::B, E::C, E::D]));
+    assert!(range.values().eq(&[1, 2, 3]));
+    assert!(range.iter().eq([(E::B, &1), (E::C, &2), (E::D, &3)]));
+    assert!(range.iter().rev().eq([(E::D, &3), (E::C, &2), (E::B, &1)]));
+    assert_eq!(range.iter().len(), 3);
+    assert!(range.into_iter().eq([(E::B, &1), (E::C, &2), (E::D, &3)]));
+}
+
+#[test]
+fn range_index() {
+    let map = map();
+    let range = map.range(E::B..=E::D);
+    assert_eq!(range[E::B], 1);
+    assert_eq!(range[&E::D], 3);
+    assert_eq!(range.get(&E::C), Some(&2));
+    assert_eq!(range.get(&E::A), None);
+    assert_eq!(range.get(&E::E), None);
+}
+
+#[test]
+#[should_panic]
+fn range_index_out_of_range() {
+    let map = map();
+    let _ = map.range(E::B..=E::D)[E::E];
+}
+
+#[test]
+fn range_debug() {
+    let map = map();
+    assert_eq!(format!("{:?}", map.range(E::D..)), "{D: 3, E: 4}");
+}
+
+#[test]
+fn range_mut() {
+    let mut map = map();
+    let mut range = map.range_mut(E::B..E::D);
+    range[E::B] = 11;
+    *range.get_mut(&E::C).unwrap() = 12;
+    assert!(range.get_mut(&E::D).is_none());
+    assert_eq!(*range, [11, 12]);
+    for (k, v) in range.iter_mut() {
+        *v += k.linearize() as u8;
+    }
+    for v in range.values_mut() {
+        *v += 100;
+    }
+    assert_eq!(map.as_ref(), [0, 112, 114, 3, 4]);
+    for (_, v) in map.range_mut(E::D..) {
+        *v = 0;
+    }
+    assert_eq!(map.as_ref(), [0, 112, 114, 0, 0]);
+    map.range_mut(..E::B).into_mut_slice()[0] = 7;
+    assert_eq!(map[E::A], 7);
+}
+
+#[test]
+fn copy_map_range() {
+    let mut map = static_copy_map! {
+        false => 0,
+        true => 1,
+    };
+    assert_eq!(*map.range(true..), [1]);
+    map.range_mut(..true)[false] = 2;
+    assert_eq!(map[false], 2);
+}
+
+#[test]
+fn variants_in() {
+    assert!(E::variants_in(E::B..=E::D).eq([E::B, E::C, E::D]));
+    assert!(E::variants_in(E::D..).eq([E::D, E::E]));
+    assert!(E::variants_in(..E::B).eq([E::A]));
+    assert_eq!(E::variants_in(E::C..E::A).count(), 0);
+    assert!(u8::variants_in(250..).eq(250..=255));
+}
+
+#[test]
+fn variants_starting_at() {
+    use linearize::iter::Variants;
+    assert!(Variants::starting_at(&E::D).eq([E::D, E::E]));
+    assert!(Variants::starting_at(&E::A).eq(E::variants()));
+}
@@ -0,0 +1,171 @@
+use {
+    linearize::{
+        AdaptiveModel, FrequencyModel, Linearize, RangeDecoder, RangeEncoder, RangeError,
+        StaticMap, StaticModel,
+    },
+    std::cmp::Ordering,
+};
+
+#[derive(Linearize, Copy, Clone, Debug, PartialEq)]
+enum Event {
+    Idle,
+    Move,
+    Jump,
+    Shoot,
+}
+
+fn events(n: usize) -> Vec<Event> {
+    let mut state = 0x1234_5678u32;
+    (0..n)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            match state % 16 {
+                0..=9 => Event::Idle,
+                10..=13 => Event::Move,
+                14 => Event::Jump,
+                _ => Event::Shoot,
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn static_model() {
+    let model = StaticModel::new(StaticMap([10, 4, 1, 1])).unwrap();
+    let events = events(1000);
+    let mut encoder = RangeEncoder::new(Vec::new());
+    encoder
+        .encode_all(&mut model.clone(), events.iter().copied())
+        .unwrap();
+    let bytes = encoder.finish().unwrap();
+    assert!(bytes.len() < 1000 * 2 / 8, "{}", bytes.len());
+    let decoded: Vec<_> = RangeDecoder::new(&bytes)
+        .decode_iter(model, events.len())
+        .collect();
+    assert_eq!(decoded, events);
+}
+
+#[test]
+fn adaptive_model() {
+    let events = events(5000);
+    let mut encoder = RangeEncoder::new(Vec::new());
+    let mut model = AdaptiveModel::new();
+    encoder
+        .encode_all(&mut model, events.iter().copied())
+        .unwrap();
+    let bytes = encoder.finish().unwrap();
+    assert!(bytes.len() < 5000 * 2 / 8, "{}", bytes.len());
+    let mut iter = RangeDecoder::new(&bytes).decode_iter(AdaptiveModel::new(), events.len());
+    assert_eq!(iter.len(), events.len());
+    let decoded: Vec<_> = iter.by_ref().collect();
+    assert_eq!(decoded, events);
+    assert_eq!(iter.model().frequencies(), model.frequencies());
+    assert!(model.frequencies()[Event::Idle] > model.frequencies()[Event::Shoot]);
+}
+
+#[test]
+fn mixed_models() {
+    let mut encoder = RangeEncoder::new(Vec::new());
+    let mut a = AdaptiveModel::<Ordering>::new();
+    let mut b = StaticModel::new(StaticMap([1, 3])).unwrap();
+    for i in 0..100 {
+        let o = [Ordering::Less, Ordering::Equal, Ordering::Greater][i % 3];
+        encoder.encode(&mut a, &o).unwrap();
+        encoder.encode(&mut b, &(i % 5 != 0)).unwrap();
+    }
+    let bytes = encoder.finish().unwrap();
+    let mut decoder = RangeDecoder::new(&bytes);
+    let mut a = AdaptiveModel::<Ordering>::new();
+    for i in 0..100 {
+        let o = [Ordering::Less, Ordering::Equal, Ordering::Greater][i % 3];
+        assert_eq!(decoder.decode(&mut a), o);
+        assert_eq!(decoder.decode(&mut b), i % 5 != 0);
+    }
+}
+
+#[test]
+fn scaling() {
+    let model = StaticModel::new(StaticMap([u32::MAX, 1, 0, 100_000])).unwrap();
+    assert!(model.total() <= 1 << 16);
+    assert_eq!(model.frequencies()[Event::Jump], 0);
+    assert_eq!(model.frequencies()[Event::Move], 1);
+    assert!(StaticModel::<bool>::new(StaticMap([0, 0])).is_none());
+    let mut adaptive = AdaptiveModel::<bool>::new();
+    for _ in 0..10_000 {
+        adaptive.update(&true);
+        assert!(adaptive.total() <= 1 << 16);
+    }
+    assert!(adaptive.frequencies()[false] >= 1);
+}
+
+#[test]
+fn errors() {
+    let mut model = StaticModel::new(StaticMap([1, 0])).unwrap();
+    let mut encoder = RangeEncoder::new(Vec::new());
+    assert_eq!(
+        encoder.encode(&mut model, &true),
+        Err(RangeError::ZeroFrequency)
+    );
+
+    let mut buf = [0; 4];
+    let mut encoder = RangeEncoder::new(&mut buf[..]);
+    encoder.encode(&mut model, &false).unwrap();
+    assert_eq!(encoder.finish().err(), Some(RangeError::BufferFull));
+
+    let mut buf = [0; 16];
+    let mut encoder = RangeEncoder::new(&mut buf[..]);
+    encoder.encode_all(&mut model, [false; 8]).unwrap();
+    let rest = encoder.finish().unwrap().len();
+    let len = 16 - rest;
+    let decoded: Vec<_> = RangeDecoder::new(&buf[..len])
+        .decode_iter(model, 8)
+        .collect();
+    assert_eq!(decoded, [false; 8]);
+
+    struct Huge;
+    impl FrequencyModel<bool> for Huge {
+        fn total(&self) -> u32 {
+            u32::MAX
+        }
+
+        fn range(&self, symbol: &bool) -> (u32, u32) {
+            (*symbol as u32, 1)
+        }
+
+        fn find(&self, target: u32) -> (bool, u32, u32) {
+            (target > 0, target.min(1), 1)
+        }
+    }
+    let mut encoder = RangeEncoder::new(Vec::new());
+    assert_eq!(
+        encoder.encode(&mut Huge, &true),
+        Err(RangeError::InvalidTotal)
+    );
+}
+
+#[test]
+fn adaptive_model_ranges() {
+    let mut model = AdaptiveModel::<u8>::new();
+    for i in 0..3000u32 {
+        model.update(&((i * i % 251) as u8));
+    }
+    let mut start = 0;
+    for (k, &freq) in model.frequencies() {
+        assert_eq!(model.range(&k), (start, freq));
+        assert_eq!(model.find(start), (k, start, freq));
+        assert_eq!(model.find(start + freq - 1), (k, start, freq));
+        start += freq;
+    }
+    assert_eq!(start, model.total());
+}
+
+#[test]
+fn malformed_input() {
+    let bytes = [0xff; 7];
+    let decoded: Vec<_> = RangeDecoder::new(&bytes)
+        .decode_iter(AdaptiveModel::<Event>::new(), 100)
+        .collect();
+    assert_eq!(decoded.len(), 100);
+}
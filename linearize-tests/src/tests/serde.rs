@@ -127,3 +127,360 @@ fn skip_none() {
     assert_eq!(map[false], Some(11));
     assert_eq!(map[true], None);
 }
+
+#[test]
+fn counter() {
+    let mut counter = linearize::StaticCounter::<bool>::new();
+    counter.add_n(true, 3);
+    let value = serde_json::to_value(counter).unwrap();
+    assert_eq!(value, json!({ "true": 3 }));
+    let new_counter: linearize::StaticCounter<bool> = serde_json::from_value(value).unwrap();
+    assert_eq!(counter, new_counter);
+}
+
+#[test]
+fn patch() {
+    let patch = linearize::StaticMapPatch::from_diff(&StaticMap([1, 2]), &StaticMap([1, 3]));
+    let value = serde_json::to_value(&patch).unwrap();
+    assert_eq!(value, json!({ "true": 3 }));
+    let new_patch: linearize::StaticMapPatch<bool, i32> = serde_json::from_value(value).unwrap();
+    assert_eq!(patch, new_patch);
+}
+
+#[test]
+fn missing_keys() {
+    #[derive(Linearize, Deserialize, Debug)]
+    enum O {
+        Less,
+        Equal,
+        Greater,
+    }
+    let value = json!({
+        "Equal": 11,
+    });
+    let err = serde_json::from_value::<StaticMap<O, u8>>(value).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Missing keys [Less, Greater] in static map"),
+        "{:?}",
+        err
+    );
+}
+
+#[test]
+fn duplicate_key() {
+    let json = r#"{"false": 1, "true": 2, "false": 3}"#;
+    let map = serde_json::from_str::<StaticMap<bool, u8>>(json).unwrap();
+    assert_eq!(map, StaticMap([3, 2]));
+
+    #[derive(Serialize, Deserialize, Debug)]
+    struct Strict {
+        #[serde(with = "linearize::serde_1::strict")]
+        map: StaticMap<bool, u8>,
+    }
+    let json = r#"{"map": {"false": 1, "true": 2, "false": 3}}"#;
+    let err = serde_json::from_str::<Strict>(json).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Duplicate key false in static map"),
+        "{:?}",
+        err
+    );
+    let json = r#"{"map": {}}"#;
+    let err = serde_json::from_str::<Strict>(json).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Missing keys [false, true] in static map"),
+        "{:?}",
+        err
+    );
+    let strict = Strict {
+        map: StaticMap([1, 2]),
+    };
+    let value = serde_json::to_value(&strict).unwrap();
+    assert_eq!(value, json!({ "map": { "false": 1, "true": 2 } }));
+    let new_strict: Strict = serde_json::from_value(value).unwrap();
+    assert_eq!(new_strict.map, strict.map);
+}
+
+#[test]
+fn strict_wrapper() {
+    use linearize::{serde_1::strict::Strict, StaticCopyMap};
+
+    type Map = Strict<StaticMap<bool, u8>>;
+    let json = r#"{"false": 1, "true": 2, "false": 3}"#;
+    let err = serde_json::from_str::<Map>(json).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Duplicate key false in static map"),
+        "{:?}",
+        err
+    );
+    let err = serde_json::from_str::<Map>(r#"{"true": 2}"#).unwrap_err();
+    assert!(
+        err.to_string().contains("Missing key false in static map"),
+        "{:?}",
+        err
+    );
+    let map: Map = serde_json::from_str(r#"{"false": 1, "true": 2}"#).unwrap();
+    assert_eq!(map, Strict(StaticMap([1, 2])));
+    assert_eq!(map[true], 2);
+    let value = serde_json::to_value(map).unwrap();
+    assert_eq!(value, json!({ "false": 1, "true": 2 }));
+    let copy: Strict<StaticCopyMap<bool, u8>> = serde_json::from_value(value).unwrap();
+    assert_eq!(copy, Strict(StaticCopyMap([1, 2])));
+    let json = r#"{"true": 1, "true": 2}"#;
+    assert!(serde_json::from_str::<Strict<StaticCopyMap<bool, u8>>>(json).is_err());
+}
+
+#[test]
+fn as_tuple() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct X {
+        #[serde(with = "linearize::serde_1::as_tuple")]
+        map: StaticMap<std::cmp::Ordering, u8>,
+        #[serde(with = "linearize::serde_1::as_tuple")]
+        copy: linearize::StaticCopyMap<bool, u16>,
+    }
+    let x = X {
+        map: StaticMap([1, 2, 3]),
+        copy: linearize::StaticCopyMap([4, 5]),
+    };
+    let value = serde_json::to_value(&x).unwrap();
+    assert_eq!(value, json!({ "map": [1, 2, 3], "copy": [4, 5] }));
+    assert_eq!(serde_json::from_value::<X>(value).unwrap(), x);
+    let bytes = postcard::to_allocvec(&x).unwrap();
+    assert_eq!(bytes, [1, 2, 3, 4, 5]);
+    assert_eq!(postcard::from_bytes::<X>(&bytes).unwrap(), x);
+    for value in [
+        json!({ "map": [1, 2], "copy": [4, 5] }),
+        json!({ "map": [1, 2, 3, 4], "copy": [4, 5] }),
+    ] {
+        let err = serde_json::from_value::<X>(value).unwrap_err();
+        assert!(
+            err.to_string().contains("a sequence of length 3"),
+            "{:?}",
+            err
+        );
+    }
+}
+
+#[test]
+fn as_seq() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct X {
+        #[serde(with = "linearize::serde_1::as_seq")]
+        map: StaticMap<std::cmp::Ordering, u8>,
+    }
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Y {
+        #[serde(with = "linearize::serde_1::as_seq")]
+        map: StaticMap<bool, u8>,
+    }
+    let x = X {
+        map: StaticMap([1, 2, 3]),
+    };
+    let value = serde_json::to_value(&x).unwrap();
+    assert_eq!(value, json!({ "map": [1, 2, 3] }));
+    assert_eq!(serde_json::from_value::<X>(value).unwrap(), x);
+    let bytes = postcard::to_allocvec(&x).unwrap();
+    assert_eq!(bytes, [3, 1, 2, 3]);
+    assert_eq!(postcard::from_bytes::<X>(&bytes).unwrap(), x);
+    assert!(postcard::from_bytes::<Y>(&bytes).is_err());
+}
+
+#[test]
+fn linearized() {
+    use linearize::{LinearizeExt, Linearized};
+    let value = serde_json::to_value(std::cmp::Ordering::Greater.linearized()).unwrap();
+    assert_eq!(value, json!(2));
+    let l: Linearized<std::cmp::Ordering> = serde_json::from_value(value).unwrap();
+    assert_eq!(l, std::cmp::Ordering::Greater.linearized());
+    let err = serde_json::from_value::<Linearized<bool>>(json!(2)).unwrap_err();
+    assert!(
+        err.to_string().contains("an index less than 2"),
+        "{:?}",
+        err
+    );
+}
+
+#[test]
+fn index_keys() {
+    #[derive(Linearize, Debug, PartialEq)]
+    struct Key {
+        verbose_field_name: bool,
+    }
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct X {
+        #[serde(with = "linearize::serde_1::index_keys")]
+        map: StaticMap<Key, u8>,
+    }
+    let x = X {
+        map: StaticMap([1, 2]),
+    };
+    let value = serde_json::to_value(&x).unwrap();
+    assert_eq!(value, json!({ "map": { "0": 1, "1": 2 } }));
+    assert_eq!(serde_json::from_value::<X>(value).unwrap(), x);
+    let err = serde_json::from_value::<X>(json!({ "map": { "0": 1 } })).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Missing key with index 1 in static map"),
+        "{:?}",
+        err
+    );
+    let err =
+        serde_json::from_value::<X>(json!({ "map": { "0": 1, "1": 2, "2": 3 } })).unwrap_err();
+    assert!(
+        err.to_string().contains("an index less than 2"),
+        "{:?}",
+        err
+    );
+}
+
+#[test]
+fn display_keys() {
+    #[derive(Linearize, Debug, PartialEq)]
+    struct Pos {
+        x: bool,
+        y: bool,
+    }
+    impl std::fmt::Display for Pos {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{},{}", self.x as u8, self.y as u8)
+        }
+    }
+    impl std::str::FromStr for Pos {
+        type Err = ();
+
+        fn from_str(s: &str) -> Result<Self, ()> {
+            let bit = |s| match s {
+                "0" => Ok(false),
+                "1" => Ok(true),
+                _ => Err(()),
+            };
+            let (x, y) = s.split_once(',').ok_or(())?;
+            Ok(Self {
+                x: bit(x)?,
+                y: bit(y)?,
+            })
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct X {
+        #[serde(with = "linearize::serde_1::display_keys")]
+        map: StaticMap<Pos, u8>,
+    }
+    let x = X {
+        map: StaticMap([1, 2, 3, 4]),
+    };
+    let value = serde_json::to_value(&x).unwrap();
+    assert_eq!(
+        value,
+        json!({ "map": { "0,0": 1, "0,1": 2, "1,0": 3, "1,1": 4 } })
+    );
+    assert_eq!(serde_json::from_value::<X>(value).unwrap(), x);
+    let json = r#"{"map": {"0,0": 1, "0,1": 2, "1,0": 3, "1,1": 4, "0,": 5}}"#;
+    let err = serde_json::from_str::<X>(json).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains(r#"Unknown key "0,", expected one of "0,0", "0,1", "1,0", "1,1""#),
+        "{:?}",
+        err
+    );
+    let err = serde_json::from_value::<X>(json!({ "map": { "0,0": 1, "0,0,0": 1 } })).unwrap_err();
+    assert!(err.to_string().contains("Unknown key"), "{:?}", err);
+    let err = serde_json::from_value::<X>(json!({ "map": { "0,0": 1 } })).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains(r#"Missing keys ["0,1", "1,0", "1,1"] in static map"#),
+        "{:?}",
+        err
+    );
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Y {
+        #[serde(with = "linearize::serde_1::display_keys")]
+        map: StaticMap<u8, u8>,
+    }
+    let err = serde_json::from_value::<Y>(json!({ "map": { "x": 1 } })).unwrap_err();
+    assert!(err.to_string().contains(r#""30", "31", ..."#), "{:?}", err);
+}
+
+#[test]
+fn migrate() {
+    use linearize::serde_1::migrate::{DroppedKeys, Migration};
+    #[derive(Linearize, Deserialize, Debug, PartialEq)]
+    enum Color {
+        Red,
+        Green,
+        Blue,
+    }
+    struct Lenient;
+    impl Migration<Color, u8> for Lenient {
+        fn alias(name: &str) -> Option<Color> {
+            (name == "Crimson").then_some(Color::Red)
+        }
+
+        fn default_value(key: &Color) -> Option<u8> {
+            (*key == Color::Blue).then_some(255)
+        }
+    }
+    struct Strict;
+    impl Migration<Color, u8> for Strict {
+        const DROPPED_KEYS: DroppedKeys = DroppedKeys::Error;
+
+        fn alias(name: &str) -> Option<Color> {
+            Lenient::alias(name)
+        }
+    }
+    #[derive(Deserialize, Debug)]
+    struct X {
+        #[serde(
+            deserialize_with = "linearize::serde_1::migrate::deserialize::<Lenient, _, _, _, _>"
+        )]
+        map: StaticMap<Color, u8>,
+    }
+    #[derive(Deserialize, Debug)]
+    struct Y {
+        #[serde(
+            deserialize_with = "linearize::serde_1::migrate::deserialize_use_default::<Strict, _, _, _, _>"
+        )]
+        map: StaticMap<Color, u8>,
+    }
+    #[derive(Deserialize, Debug)]
+    struct Z {
+        #[serde(
+            deserialize_with = "linearize::serde_1::migrate::deserialize_skip_none::<Lenient, _, _, _, _>"
+        )]
+        map: StaticMap<Color, Option<u8>>,
+    }
+    let old = json!({ "map": { "Crimson": 1, "Green": 2, "Yellow": 3 } });
+    let x = serde_json::from_value::<X>(old.clone()).unwrap();
+    assert_eq!(x.map, StaticMap([1, 2, 255]));
+    let new = json!({ "map": { "Red": 1, "Green": 2, "Blue": 3 } });
+    let x = serde_json::from_value::<X>(new).unwrap();
+    assert_eq!(x.map, StaticMap([1, 2, 3]));
+    let err = serde_json::from_value::<X>(json!({ "map": { "Red": 1 } })).unwrap_err();
+    assert!(
+        err.to_string().contains("Missing key Green in static map"),
+        "{:?}",
+        err
+    );
+    let err = serde_json::from_value::<X>(json!({ "map": {} })).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Missing keys [Red, Green] in static map"),
+        "{:?}",
+        err
+    );
+    let err = serde_json::from_value::<Y>(old).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains(r#"Unknown key "Yellow" in static map"#),
+        "{:?}",
+        err
+    );
+    let y = serde_json::from_value::<Y>(json!({ "map": { "Crimson": 1 } })).unwrap();
+    assert_eq!(y.map, StaticMap([1, 0, 0]));
+    let z = serde_json::from_value::<Z>(json!({ "map": { "Green": 2 } })).unwrap();
+    assert_eq!(z.map, StaticMap([None, Some(2), Some(255)]));
+}
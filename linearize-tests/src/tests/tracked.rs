@@ -0,0 +1,89 @@
+use {
+    linearize::{LinearizeExt, StaticMap, TrackedStaticMap},
+    std::cmp::Ordering,
+};
+
+#[test]
+fn index_mut() {
+    let mut map = TrackedStaticMap::new(StaticMap::<Ordering, i32>::default());
+    assert!(!map.has_dirty());
+    assert_eq!(map[Ordering::Less], 0);
+    assert!(!map.is_dirty(&Ordering::Less));
+    map[Ordering::Less] = 1;
+    map[&Ordering::Equal] += 0;
+    assert_eq!(map.set(&Ordering::Less, 2), 1);
+    assert!(map.is_dirty(&Ordering::Less));
+    assert!(map.is_dirty(&Ordering::Equal));
+    assert!(!map.is_dirty(&Ordering::Greater));
+    assert_eq!(
+        map.dirty_keys().collect::<Vec<_>>(),
+        [Ordering::Less, Ordering::Equal]
+    );
+    assert_eq!(map.take_dirty(), StaticMap([true, true, false]));
+    assert!(!map.has_dirty());
+    map[Ordering::Greater.linearized()] = 3;
+    assert_eq!(map.dirty_keys().collect::<Vec<_>>(), [Ordering::Greater]);
+    map.clear_dirty();
+    assert_eq!(map.dirty_keys().count(), 0);
+    assert_eq!(map.into_inner(), StaticMap([2, 0, 3]));
+}
+
+#[test]
+fn iterators() {
+    let mut map = TrackedStaticMap::new(StaticMap::<Ordering, i32>::default());
+    let (k, v) = map.iter_mut().next().unwrap();
+    assert_eq!(k, Ordering::Less);
+    *v = 1;
+    assert_eq!(map.dirty_keys().collect::<Vec<_>>(), [Ordering::Less]);
+    map.clear_dirty();
+    *map.values_mut().next_back().unwrap() = 2;
+    assert_eq!(map.dirty_keys().collect::<Vec<_>>(), [Ordering::Greater]);
+    for (_, v) in &mut map {
+        *v += 1;
+    }
+    assert_eq!(map.dirty_keys().count(), 3);
+    assert_eq!(*map.as_map(), StaticMap([2, 1, 3]));
+    map.clear_dirty();
+    map.mark_dirty(&Ordering::Equal);
+    assert_eq!(map.dirty_keys().collect::<Vec<_>>(), [Ordering::Equal]);
+    map.mark_all_dirty();
+    assert_eq!(map.dirty_keys().count(), 3);
+}
+
+#[test]
+fn changes_since() {
+    let mut map = TrackedStaticMap::new(StaticMap([1, 2, 3]));
+    let snapshot = map.as_map().clone();
+    map[Ordering::Less] = 1;
+    map[Ordering::Greater] = 4;
+    assert_eq!(map.dirty_keys().count(), 2);
+    assert_eq!(
+        map.changes_since(&snapshot).collect::<Vec<_>>(),
+        [(Ordering::Greater, &4)]
+    );
+}
+
+#[test]
+fn debug() {
+    let mut map = TrackedStaticMap::new(StaticMap([1, 2]));
+    map[true] = 3;
+    assert_eq!(
+        format!("{:?}", map),
+        "TrackedStaticMap { map: {false: 1, true: 3}, dirty: {true} }"
+    );
+}
+
+#[test]
+fn many_keys() {
+    let mut map = TrackedStaticMap::new(StaticMap::<u8, u8>::default());
+    map[63] = 1;
+    map[64] = 1;
+    *map.values_mut().next_back().unwrap() = 1;
+    assert_eq!(map.dirty_keys().collect::<Vec<_>>(), [63, 64, 255]);
+    assert_eq!(map.dirty_keys().len(), 3);
+    map.mark_all_dirty();
+    assert_eq!(map.dirty_keys().count(), 256);
+    let dirty = map.take_dirty();
+    assert!(dirty.values().all(|d| *d));
+    assert!(!map.has_dirty());
+}
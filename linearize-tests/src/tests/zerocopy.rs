@@ -0,0 +1,48 @@
+use {
+    linearize::{Linearize, StaticCopyMap, StaticMap},
+    std::cmp::Ordering,
+    zerocopy::{FromBytes, FromZeros, Immutable, IntoBytes, KnownLayout, TryFromBytes},
+};
+
+#[derive(
+    Linearize, TryFromBytes, IntoBytes, Immutable, KnownLayout, Copy, Clone, Debug, PartialEq,
+)]
+#[linearize(zerocopy)]
+#[repr(u8)]
+enum Level {
+    Low,
+    Mid,
+    High,
+}
+
+#[test]
+fn from_bytes() {
+    let bytes = [1, 0, 2, 0, 3, 0];
+    let map = StaticMap::<Ordering, u16>::ref_from_bytes(&bytes).unwrap();
+    assert_eq!(map[Ordering::Greater], u16::from_ne_bytes([3, 0]));
+    assert_eq!(map.as_bytes(), bytes);
+    let copy = StaticCopyMap::<bool, u8>::read_from_bytes(&[4, 5]).unwrap();
+    assert_eq!(copy, StaticCopyMap([4, 5]));
+    assert!(StaticCopyMap::<bool, u8>::ref_from_bytes(&[1, 2, 3]).is_err());
+}
+
+#[test]
+fn from_zeros() {
+    let map = StaticMap::<bool, u64>::new_zeroed();
+    assert_eq!(map, StaticMap([0, 0]));
+    let mut copy = StaticCopyMap::<bool, u32>([1, 2]);
+    copy.zero();
+    assert_eq!(copy, StaticCopyMap([0, 0]));
+}
+
+#[test]
+fn try_from_bytes() {
+    let map = StaticCopyMap::<bool, Level>::try_ref_from_bytes(&[0, 2]).unwrap();
+    assert_eq!(map[false], Level::Low);
+    assert_eq!(map[true], Level::High);
+    assert_eq!(map.as_bytes(), [0, 2]);
+    assert!(StaticCopyMap::<bool, Level>::try_ref_from_bytes(&[0, 3]).is_err());
+    let map = StaticMap::<Level, Level>::try_read_from_bytes(&[2, 1, 0]).unwrap();
+    assert_eq!(map, StaticMap([Level::High, Level::Mid, Level::Low]));
+    assert!(StaticMap::<Level, Level>::try_read_from_bytes(&[2, 1, 255]).is_err());
+}
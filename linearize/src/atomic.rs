@@ -0,0 +1,389 @@
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::AtomicBool;
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::AtomicUsize;
+use {
+    crate::{Linearize, LinearizeExt, StaticCopyMap, StaticMap},
+    core::{
+        fmt::{Debug, Formatter},
+        ops::{Deref, DerefMut, Index},
+        sync::atomic::{AtomicU64, Ordering},
+    },
+};
+
+/// An atomic value that can be stored in a [`StaticAtomicMap`].
+///
+/// This trait is sealed and implemented for `AtomicU64`, `AtomicUsize`, `AtomicBool`,
+/// and [`CachePadded`] wrappers of these types.
+pub trait Atomic: Sync + Send + sealed::Sealed {
+    /// The non-atomic value type.
+    type Value: Copy + Default;
+
+    /// Creates a new atomic value.
+    fn new(value: Self::Value) -> Self;
+
+    /// Loads the value.
+    fn load(&self, order: Ordering) -> Self::Value;
+
+    /// Stores a value.
+    fn store(&self, value: Self::Value, order: Ordering);
+
+    /// Stores a value and returns the previous value.
+    fn swap(&self, value: Self::Value, order: Ordering) -> Self::Value;
+
+    /// Consumes the atomic value and returns the contained value.
+    fn into_inner(self) -> Self::Value;
+}
+
+/// An [`Atomic`] that supports wrapping addition and subtraction.
+pub trait AtomicAdd: Atomic {
+    /// Adds to the value and returns the previous value.
+    fn fetch_add(&self, value: Self::Value, order: Ordering) -> Self::Value;
+
+    /// Subtracts from the value and returns the previous value.
+    fn fetch_sub(&self, value: Self::Value, order: Ordering) -> Self::Value;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_atomic {
+    ($cfg:literal, $ty:ty, $value:ty) => {
+        #[cfg(target_has_atomic = $cfg)]
+        impl sealed::Sealed for $ty {}
+
+        #[cfg(target_has_atomic = $cfg)]
+        impl Atomic for $ty {
+            type Value = $value;
+
+            #[inline]
+            fn new(value: Self::Value) -> Self {
+                <$ty>::new(value)
+            }
+
+            #[inline]
+            fn load(&self, order: Ordering) -> Self::Value {
+                <$ty>::load(self, order)
+            }
+
+            #[inline]
+            fn store(&self, value: Self::Value, order: Ordering) {
+                <$ty>::store(self, value, order)
+            }
+
+            #[inline]
+            fn swap(&self, value: Self::Value, order: Ordering) -> Self::Value {
+                <$ty>::swap(self, value, order)
+            }
+
+            #[inline]
+            fn into_inner(self) -> Self::Value {
+                <$ty>::into_inner(self)
+            }
+        }
+    };
+}
+
+macro_rules! impl_atomic_add {
+    ($cfg:literal, $ty:ty) => {
+        #[cfg(target_has_atomic = $cfg)]
+        impl AtomicAdd for $ty {
+            #[inline]
+            fn fetch_add(&self, value: Self::Value, order: Ordering) -> Self::Value {
+                <$ty>::fetch_add(self, value, order)
+            }
+
+            #[inline]
+            fn fetch_sub(&self, value: Self::Value, order: Ordering) -> Self::Value {
+                <$ty>::fetch_sub(self, value, order)
+            }
+        }
+    };
+}
+
+impl_atomic!("64", AtomicU64, u64);
+impl_atomic!("ptr", AtomicUsize, usize);
+impl_atomic!("8", AtomicBool, bool);
+impl_atomic_add!("64", AtomicU64);
+impl_atomic_add!("ptr", AtomicUsize);
+
+/// A wrapper that aligns its contents to a cache line.
+///
+/// Using `CachePadded<AtomicU64>` as the value type of a [`StaticAtomicMap`] prevents
+/// false sharing between threads that update different keys, at the cost of using
+/// a full cache line per key.
+#[cfg_attr(
+    any(
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "powerpc64",
+    ),
+    repr(align(128))
+)]
+#[cfg_attr(
+    not(any(
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "powerpc64",
+    )),
+    repr(align(64))
+)]
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub struct CachePadded<T>(
+    /// The wrapped value.
+    pub T,
+);
+
+impl<T> Deref for CachePadded<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for CachePadded<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<A> sealed::Sealed for CachePadded<A> where A: Atomic {}
+
+impl<A> Atomic for CachePadded<A>
+where
+    A: Atomic,
+{
+    type Value = A::Value;
+
+    #[inline]
+    fn new(value: Self::Value) -> Self {
+        Self(A::new(value))
+    }
+
+    #[inline]
+    fn load(&self, order: Ordering) -> Self::Value {
+        self.0.load(order)
+    }
+
+    #[inline]
+    fn store(&self, value: Self::Value, order: Ordering) {
+        self.0.store(value, order)
+    }
+
+    #[inline]
+    fn swap(&self, value: Self::Value, order: Ordering) -> Self::Value {
+        self.0.swap(value, order)
+    }
+
+    #[inline]
+    fn into_inner(self) -> Self::Value {
+        self.0.into_inner()
+    }
+}
+
+impl<A> AtomicAdd for CachePadded<A>
+where
+    A: AtomicAdd,
+{
+    #[inline]
+    fn fetch_add(&self, value: Self::Value, order: Ordering) -> Self::Value {
+        self.0.fetch_add(value, order)
+    }
+
+    #[inline]
+    fn fetch_sub(&self, value: Self::Value, order: Ordering) -> Self::Value {
+        self.0.fetch_sub(value, order)
+    }
+}
+
+/// A map of atomic values.
+///
+/// The value type `A` can be `AtomicU64` (the default), `AtomicUsize`, `AtomicBool`, or
+/// a [`CachePadded`] wrapper of these types. All operations take `&self` and can
+/// therefore be used concurrently from multiple threads.
+///
+/// This type is only available on targets that support 64-bit atomics.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::sync::atomic::Ordering::Relaxed;
+/// # use linearize::{CachePadded, StaticAtomicMap};
+/// # use std::sync::atomic::AtomicU64;
+/// let map = StaticAtomicMap::<bool, CachePadded<AtomicU64>>::new();
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| {
+///             map.fetch_add(&true, 1, Relaxed);
+///         });
+///     }
+/// });
+/// assert_eq!(map.load(&true, Relaxed), 4);
+/// assert_eq!(map.snapshot(Relaxed)[false], 0);
+/// ```
+#[repr(transparent)]
+pub struct StaticAtomicMap<L, A = AtomicU64>(
+    /// The underlying map.
+    pub StaticMap<L, A>,
+)
+where
+    L: Linearize;
+
+impl<L, A> StaticAtomicMap<L, A>
+where
+    L: Linearize,
+    A: Atomic,
+{
+    /// Creates a new map with all values set to their default value.
+    #[inline]
+    pub fn new() -> Self {
+        Self::from_fn(|_| A::Value::default())
+    }
+
+    /// Creates a map from a callback.
+    #[inline]
+    pub fn from_fn(mut cb: impl FnMut(L) -> A::Value) -> Self {
+        Self(StaticMap::from_fn(|l| A::new(cb(l))))
+    }
+
+    /// Returns a reference to the atomic value of `key`.
+    #[inline]
+    pub fn get(&self, key: &L) -> &A {
+        &self.0[key]
+    }
+
+    /// Loads the value of `key`.
+    #[inline]
+    pub fn load(&self, key: &L, order: Ordering) -> A::Value {
+        self.0[key].load(order)
+    }
+
+    /// Stores the value of `key`.
+    #[inline]
+    pub fn store(&self, key: &L, value: A::Value, order: Ordering) {
+        self.0[key].store(value, order)
+    }
+
+    /// Stores the value of `key` and returns the previous value.
+    #[inline]
+    pub fn swap(&self, key: &L, value: A::Value, order: Ordering) -> A::Value {
+        self.0[key].swap(value, order)
+    }
+
+    /// Adds to the value of `key` and returns the previous value.
+    ///
+    /// The addition wraps on overflow.
+    #[inline]
+    pub fn fetch_add(&self, key: &L, value: A::Value, order: Ordering) -> A::Value
+    where
+        A: AtomicAdd,
+    {
+        self.0[key].fetch_add(value, order)
+    }
+
+    /// Subtracts from the value of `key` and returns the previous value.
+    ///
+    /// The subtraction wraps on overflow.
+    #[inline]
+    pub fn fetch_sub(&self, key: &L, value: A::Value, order: Ordering) -> A::Value
+    where
+        A: AtomicAdd,
+    {
+        self.0[key].fetch_sub(value, order)
+    }
+
+    /// Loads all values.
+    ///
+    /// Each value is loaded individually. If other threads modify the map concurrently,
+    /// the snapshot does not necessarily correspond to a state the map was in at any
+    /// single point in time.
+    #[inline]
+    pub fn snapshot(&self, order: Ordering) -> StaticCopyMap<L, A::Value> {
+        StaticCopyMap::from_fn(|l: L| self.0[l.linearized()].load(order))
+    }
+
+    /// Sets all values to their default value.
+    #[inline]
+    pub fn reset(&self, order: Ordering) {
+        for a in self.0.values() {
+            a.store(A::Value::default(), order);
+        }
+    }
+
+    /// Sets all values to their default value and returns the previous values.
+    ///
+    /// Each value is swapped individually. Concurrent updates are never lost: each
+    /// update is either contained in the returned map or remains in this map.
+    #[inline]
+    pub fn take(&self, order: Ordering) -> StaticCopyMap<L, A::Value> {
+        StaticCopyMap::from_fn(|l: L| self.0[l.linearized()].swap(A::Value::default(), order))
+    }
+
+    /// Consumes the map and returns the contained values.
+    #[inline]
+    pub fn into_inner(self) -> StaticCopyMap<L, A::Value> {
+        self.0.map_values(A::into_inner).into_copy()
+    }
+}
+
+impl<L, A> Default for StaticAtomicMap<L, A>
+where
+    L: Linearize,
+    A: Atomic,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Formats the values loaded with `Ordering::Relaxed`.
+impl<L, A> Debug for StaticAtomicMap<L, A>
+where
+    L: Linearize + Debug,
+    A: Atomic,
+    A::Value: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.snapshot(Ordering::Relaxed), f)
+    }
+}
+
+impl<L, A> From<StaticCopyMap<L, A::Value>> for StaticAtomicMap<L, A>
+where
+    L: Linearize,
+    A: Atomic,
+{
+    #[inline]
+    fn from(value: StaticCopyMap<L, A::Value>) -> Self {
+        Self(value.into_static_map().map_values(A::new))
+    }
+}
+
+impl<L, A> Index<&'_ L> for StaticAtomicMap<L, A>
+where
+    L: Linearize,
+{
+    type Output = A;
+
+    #[inline]
+    fn index(&self, index: &L) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<L, A> Index<L> for StaticAtomicMap<L, A>
+where
+    L: Linearize,
+{
+    type Output = A;
+
+    #[inline]
+    fn index(&self, index: L) -> &Self::Output {
+        &self.0[index]
+    }
+}
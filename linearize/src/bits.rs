@@ -0,0 +1,271 @@
+use {
+    crate::{Linearize, LinearizeExt, StaticCopyMap, StaticMap},
+    core::fmt::{Display, Formatter},
+};
+
+/// An error returned by [`BitWriter`] and [`BitReader`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BitError {
+    /// The buffer does not have enough bits left.
+    UnexpectedEnd,
+    /// The decoded index is not less than the [`LENGTH`](Linearize::LENGTH) of the type.
+    InvalidValue,
+}
+
+impl Display for BitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BitError::UnexpectedEnd => f.write_str("Unexpected end of buffer"),
+            BitError::InvalidValue => f.write_str("Decoded index is out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BitError {}
+
+/// Returns the number of bits used to encode a value of type `L`.
+///
+/// This is `ceil(log2(L::LENGTH))`. Types with at most one value use zero bits.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::cmp::Ordering;
+/// # use linearize::bit_width;
+/// assert_eq!(bit_width::<()>(), 0);
+/// assert_eq!(bit_width::<bool>(), 1);
+/// assert_eq!(bit_width::<Ordering>(), 2);
+/// assert_eq!(bit_width::<u8>(), 8);
+/// ```
+#[inline]
+pub const fn bit_width<L>() -> u32
+where
+    L: Linearize,
+{
+    match L::LENGTH {
+        0 | 1 => 0,
+        n => usize::BITS - (n - 1).leading_zeros(),
+    }
+}
+
+/// Returns the number of bits used to encode the values of a `StaticMap<L, V>` or `None`
+/// if that number overflows `usize`.
+#[inline]
+fn map_bits<L, V>() -> Option<usize>
+where
+    L: Linearize,
+    V: Linearize,
+{
+    L::LENGTH.checked_mul(bit_width::<V>() as usize)
+}
+
+/// A writer that packs [`Linearize`] values into a byte buffer.
+///
+/// Each value of type `L` occupies [`bit_width::<L>()`](bit_width) bits. Values are
+/// packed without padding, starting at the least significant bit of the first byte.
+/// Values of different types can be mixed freely and are decoded by reading the same
+/// sequence of types with a [`BitReader`].
+///
+/// # Example
+///
+/// ```rust
+/// # use std::cmp::Ordering;
+/// # use linearize::{BitReader, BitWriter, StaticCopyMap};
+/// let mut buf = [0; 2];
+/// let mut writer = BitWriter::new(&mut buf);
+/// writer.write(&true).unwrap();
+/// writer.write(&Ordering::Greater).unwrap();
+/// writer.write_map(&StaticCopyMap::<bool, _>([false, true])).unwrap();
+/// assert_eq!(writer.bits_written(), 5);
+/// assert_eq!(writer.finish(), [0b10101]);
+///
+/// let mut reader = BitReader::new(&buf);
+/// assert_eq!(reader.read::<bool>(), Ok(true));
+/// assert_eq!(reader.read::<Ordering>(), Ok(Ordering::Greater));
+/// assert_eq!(reader.read_map::<bool, bool>(), Ok(StaticCopyMap([false, true])));
+/// ```
+#[derive(Debug)]
+pub struct BitWriter<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> BitWriter<'a> {
+    /// Creates a writer that writes to `buf`.
+    #[inline]
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// Returns the number of bits written so far.
+    #[inline]
+    pub fn bits_written(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the number of bytes that contain written bits.
+    #[inline]
+    pub fn bytes_written(&self) -> usize {
+        self.pos.div_ceil(8)
+    }
+
+    /// Returns the number of bits that can still be written.
+    #[inline]
+    pub fn remaining_bits(&self) -> usize {
+        self.buf.len() * 8 - self.pos
+    }
+
+    /// Writes a value.
+    ///
+    /// If the buffer is too small, nothing is written.
+    #[inline]
+    pub fn write<L>(&mut self, value: &L) -> Result<(), BitError>
+    where
+        L: Linearize,
+    {
+        let bits = bit_width::<L>();
+        if self.remaining_bits() < bits as usize {
+            return Err(BitError::UnexpectedEnd);
+        }
+        self.write_bits(value.linearize(), bits);
+        Ok(())
+    }
+
+    /// Writes the values of a map in the order of their keys.
+    ///
+    /// The keys themselves are not written. If the buffer is too small, nothing is
+    /// written.
+    pub fn write_map<L, V>(&mut self, map: &StaticMap<L, V>) -> Result<(), BitError>
+    where
+        L: Linearize,
+        V: Linearize,
+    {
+        let bits = bit_width::<V>();
+        if map_bits::<L, V>().is_none_or(|n| n > self.remaining_bits()) {
+            return Err(BitError::UnexpectedEnd);
+        }
+        for value in map.values() {
+            self.write_bits(value.linearize(), bits);
+        }
+        Ok(())
+    }
+
+    /// Consumes the writer and returns the bytes that contain written bits.
+    ///
+    /// Unused bits in the last byte are set to zero.
+    #[inline]
+    pub fn finish(self) -> &'a mut [u8] {
+        let len = self.bytes_written();
+        if self.pos % 8 != 0 {
+            self.buf[self.pos / 8] &= (1 << (self.pos % 8)) - 1;
+        }
+        &mut self.buf[..len]
+    }
+
+    fn write_bits(&mut self, mut value: usize, mut bits: u32) {
+        while bits > 0 {
+            let byte = &mut self.buf[self.pos / 8];
+            let offset = (self.pos % 8) as u32;
+            let n = bits.min(8 - offset);
+            let mask = (((1u16 << n) - 1) as u8) << offset;
+            *byte = (*byte & !mask) | (((value as u8) << offset) & mask);
+            value >>= n;
+            bits -= n;
+            self.pos += n as usize;
+        }
+    }
+}
+
+/// A reader that unpacks [`Linearize`] values from a byte buffer.
+///
+/// This is the inverse of [`BitWriter`]. Decoded indices are checked with
+/// [`from_linear`](LinearizeExt::from_linear), so malformed input results in an error
+/// instead of an invalid value.
+#[derive(Copy, Clone, Debug)]
+pub struct BitReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Creates a reader that reads from `buf`.
+    #[inline]
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// Returns the number of bits read so far.
+    #[inline]
+    pub fn bits_read(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the number of bits that can still be read.
+    #[inline]
+    pub fn remaining_bits(&self) -> usize {
+        self.buf.len() * 8 - self.pos
+    }
+
+    /// Reads a value.
+    ///
+    /// If an error occurs, the position of the reader is not changed.
+    #[inline]
+    pub fn read<L>(&mut self) -> Result<L, BitError>
+    where
+        L: Linearize,
+    {
+        let bits = bit_width::<L>();
+        if self.remaining_bits() < bits as usize {
+            return Err(BitError::UnexpectedEnd);
+        }
+        let pos = self.pos;
+        match L::from_linear(self.read_bits(bits)) {
+            Some(v) => Ok(v),
+            None => {
+                self.pos = pos;
+                Err(BitError::InvalidValue)
+            }
+        }
+    }
+
+    /// Reads the values of a map in the order of their keys.
+    ///
+    /// If an error occurs, the position of the reader is not changed.
+    pub fn read_map<L, V>(&mut self) -> Result<StaticCopyMap<L, V>, BitError>
+    where
+        L: Linearize,
+        V: Linearize + Copy,
+    {
+        let bits = bit_width::<V>();
+        if map_bits::<L, V>().is_none_or(|n| n > self.remaining_bits()) {
+            return Err(BitError::UnexpectedEnd);
+        }
+        let mut check = *self;
+        for _ in 0..L::LENGTH {
+            if check.read_bits(bits) >= V::LENGTH {
+                return Err(BitError::InvalidValue);
+            }
+        }
+        Ok(StaticCopyMap::from_fn(|_| unsafe {
+            // SAFETY: We've checked above that all indices are less than V::LENGTH.
+            V::from_linear_unchecked(self.read_bits(bits))
+        }))
+    }
+
+    fn read_bits(&mut self, mut bits: u32) -> usize {
+        let mut value = 0;
+        let mut shift = 0;
+        while bits > 0 {
+            let byte = self.buf[self.pos / 8];
+            let offset = (self.pos % 8) as u32;
+            let n = bits.min(8 - offset);
+            let chunk = (byte >> offset) as usize & ((1 << n) - 1);
+            value |= chunk << shift;
+            shift += n;
+            bits -= n;
+            self.pos += n as usize;
+        }
+        value
+    }
+}
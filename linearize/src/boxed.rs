@@ -0,0 +1,509 @@
+use {
+    crate::{
+        map::iters::{Iter, IterMut},
+        Linearize, StaticMap,
+    },
+    alloc::{boxed::Box, vec::Vec},
+    core::{
+        borrow::{Borrow, BorrowMut},
+        cmp::Ordering,
+        fmt::{Debug, Formatter},
+        hash::{Hash, Hasher},
+        ops::{Deref, DerefMut, Index, IndexMut},
+        ptr,
+    },
+};
+
+/// A [`StaticMap`] that is stored on the heap.
+///
+/// This type dereferences to `StaticMap<L, T>` and therefore has the same API. Unlike
+/// `Box<StaticMap<L, T>>`, all constructors of this type, including the [`Default`] and
+/// [`Clone`] implementations, initialize the map directly on the heap. This makes it
+/// possible to use maps that are too large for the stack.
+///
+/// This type is only available with the `alloc` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use linearize::BoxedStaticMap;
+/// let mut map = BoxedStaticMap::<u16, [u64; 8]>::default();
+/// map[1234][0] = 1;
+/// assert_eq!(map.values().filter(|v| v[0] == 1).count(), 1);
+/// ```
+#[repr(transparent)]
+pub struct BoxedStaticMap<L, T>(
+    /// The underlying map.
+    pub Box<StaticMap<L, T>>,
+)
+where
+    L: Linearize;
+
+struct Guard<T> {
+    ptr: *mut T,
+    initialized: usize,
+}
+
+impl<T> Drop for Guard<T> {
+    fn drop(&mut self) {
+        unsafe {
+            // SAFETY: The first `initialized` elements starting at ptr have been
+            // initialized.
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr, self.initialized));
+        }
+    }
+}
+
+impl<L, T> StaticMap<L, T>
+where
+    L: Linearize,
+{
+    /// Creates a map on the heap from a callback.
+    ///
+    /// Unlike `Box::new(StaticMap::from_fn(cb))`, this function never stores the map on
+    /// the stack.
+    ///
+    /// This function is only available with the `alloc` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::StaticMap;
+    /// let map = StaticMap::boxed_from_fn(|l: u16| [l as u8; 64]);
+    /// assert_eq!(map[300], [44; 64]);
+    /// ```
+    pub fn boxed_from_fn(mut cb: impl FnMut(L) -> T) -> Box<Self> {
+        let mut map = Box::<Self>::new_uninit();
+        let mut guard = Guard {
+            ptr: map.as_mut_ptr().cast::<T>(),
+            initialized: 0,
+        };
+        while guard.initialized < L::LENGTH {
+            let i = guard.initialized;
+            let value = cb(unsafe {
+                // SAFETY: i < L::LENGTH.
+                L::from_linear_unchecked(i)
+            });
+            unsafe {
+                // SAFETY:
+                // - StaticMap<L, T> is a transparent wrapper around L::Storage<T>.
+                // - L::Storage<T> is required to be [T; L::LENGTH].
+                // - Therefore, guard.ptr is a pointer to [MaybeUninit<T>; L::LENGTH].
+                // - Since i < L::LENGTH, the `add` is in bounds.
+                guard.ptr.add(i).write(value);
+            }
+            guard.initialized += 1;
+        }
+        core::mem::forget(guard);
+        unsafe {
+            // SAFETY: All L::LENGTH elements have been initialized in the loop above.
+            map.assume_init()
+        }
+    }
+
+    /// Creates a map on the heap that contains the default value for each key.
+    ///
+    /// Unlike `Box::new(StaticMap::default())`, this function never stores the map on
+    /// the stack.
+    ///
+    /// This function is only available with the `alloc` feature.
+    #[inline]
+    pub fn new_boxed_default() -> Box<Self>
+    where
+        T: Default,
+    {
+        Self::boxed_from_fn(|_| T::default())
+    }
+
+    /// Converts a boxed slice to a boxed map without copying.
+    ///
+    /// Returns the slice if its length is not `L::LENGTH`.
+    ///
+    /// This function is only available with the `alloc` feature.
+    #[inline]
+    pub fn try_from_boxed_slice(slice: Box<[T]>) -> Result<Box<Self>, Box<[T]>> {
+        if slice.len() != L::LENGTH {
+            return Err(slice);
+        }
+        let ptr = Box::into_raw(slice);
+        unsafe {
+            // SAFETY:
+            // - StaticMap<L, T> is a transparent wrapper around L::Storage<T>.
+            // - L::Storage<T> is required to be [T; L::LENGTH].
+            // - The slice has length L::LENGTH and therefore the same layout.
+            Ok(Box::from_raw(ptr.cast::<Self>()))
+        }
+    }
+
+    /// Converts a vector to a boxed map.
+    ///
+    /// If the capacity of the vector is `L::LENGTH`, the allocation of the vector is
+    /// reused. Returns the vector if its length is not `L::LENGTH`.
+    ///
+    /// This function is only available with the `alloc` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::StaticMap;
+    /// let map = StaticMap::<bool, _>::try_from_vec(vec![1, 2]).unwrap();
+    /// assert_eq!(map[true], 2);
+    /// assert_eq!(StaticMap::<bool, _>::try_from_vec(vec![1]), Err(vec![1]));
+    /// ```
+    #[inline]
+    pub fn try_from_vec(vec: Vec<T>) -> Result<Box<Self>, Vec<T>> {
+        if vec.len() != L::LENGTH {
+            return Err(vec);
+        }
+        Self::try_from_boxed_slice(vec.into_boxed_slice()).map_err(Vec::from)
+    }
+
+    /// Clones this map into a new map on the heap.
+    ///
+    /// Unlike `Box::new(self.clone())`, this function never stores the map on the
+    /// stack.
+    ///
+    /// This function is only available with the `alloc` feature.
+    #[inline]
+    pub fn clone_boxed(&self) -> Box<Self>
+    where
+        T: Clone,
+    {
+        Self::boxed_from_fn(|l| self[&l].clone())
+    }
+}
+
+impl<L, T> TryFrom<Box<[T]>> for Box<StaticMap<L, T>>
+where
+    L: Linearize,
+{
+    type Error = Box<[T]>;
+
+    #[inline]
+    fn try_from(value: Box<[T]>) -> Result<Self, Self::Error> {
+        StaticMap::try_from_boxed_slice(value)
+    }
+}
+
+impl<L, T> TryFrom<Vec<T>> for Box<StaticMap<L, T>>
+where
+    L: Linearize,
+{
+    type Error = Vec<T>;
+
+    #[inline]
+    fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
+        StaticMap::try_from_vec(value)
+    }
+}
+
+impl<L, T> BoxedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    /// Creates a map from a callback.
+    ///
+    /// See [`StaticMap::boxed_from_fn`].
+    #[inline]
+    pub fn from_fn(cb: impl FnMut(L) -> T) -> Self {
+        Self(StaticMap::boxed_from_fn(cb))
+    }
+
+    /// Converts a vector to a map.
+    ///
+    /// See [`StaticMap::try_from_vec`].
+    #[inline]
+    pub fn try_from_vec(vec: Vec<T>) -> Result<Self, Vec<T>> {
+        StaticMap::try_from_vec(vec).map(Self)
+    }
+
+    /// Returns the underlying box.
+    #[inline]
+    pub fn into_box(self) -> Box<StaticMap<L, T>> {
+        self.0
+    }
+}
+
+impl<L, T> Deref for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    type Target = StaticMap<L, T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<L, T> DerefMut for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<L, T> AsRef<StaticMap<L, T>> for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn as_ref(&self) -> &StaticMap<L, T> {
+        &self.0
+    }
+}
+
+impl<L, T> AsMut<StaticMap<L, T>> for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut StaticMap<L, T> {
+        &mut self.0
+    }
+}
+
+impl<L, T> Borrow<StaticMap<L, T>> for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn borrow(&self) -> &StaticMap<L, T> {
+        &self.0
+    }
+}
+
+impl<L, T> BorrowMut<StaticMap<L, T>> for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut StaticMap<L, T> {
+        &mut self.0
+    }
+}
+
+impl<L, T> From<Box<StaticMap<L, T>>> for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn from(value: Box<StaticMap<L, T>>) -> Self {
+        Self(value)
+    }
+}
+
+impl<L, T> From<StaticMap<L, T>> for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn from(value: StaticMap<L, T>) -> Self {
+        Self(Box::new(value))
+    }
+}
+
+impl<L, T> From<BoxedStaticMap<L, T>> for Box<StaticMap<L, T>>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn from(value: BoxedStaticMap<L, T>) -> Self {
+        value.0
+    }
+}
+
+impl<L, T> TryFrom<Vec<T>> for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    type Error = Vec<T>;
+
+    #[inline]
+    fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
+        Self::try_from_vec(value)
+    }
+}
+
+impl<L, T> Default for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+    T: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self(StaticMap::new_boxed_default())
+    }
+}
+
+impl<L, T> Clone for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+    T: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone_boxed())
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0);
+    }
+}
+
+impl<L, T> Debug for BoxedStaticMap<L, T>
+where
+    L: Linearize + Debug,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&*self.0, f)
+    }
+}
+
+impl<L, T> PartialEq for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        *self.0 == *other.0
+    }
+}
+
+impl<L, T> Eq for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+    T: Eq,
+{
+}
+
+impl<L, T> PartialOrd for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+    T: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<L, T> Ord for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+    T: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<L, T> Hash for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+    T: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<L, T> Index<&'_ L> for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: &L) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<L, T> Index<L> for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: L) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<L, T> IndexMut<&'_ L> for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn index_mut(&mut self, index: &L) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<L, T> IndexMut<L> for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn index_mut(&mut self, index: L) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<L, T> FromIterator<(L, T)> for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+    T: Default,
+{
+    fn from_iter<I: IntoIterator<Item = (L, T)>>(iter: I) -> Self {
+        let mut res = Self::default();
+        res.0.extend(iter);
+        res
+    }
+}
+
+impl<L, T> Extend<(L, T)> for BoxedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = (L, T)>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<'a, L, T> IntoIterator for &'a BoxedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    type Item = (L, &'a T);
+    type IntoIter = Iter<'a, L, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, L, T> IntoIterator for &'a mut BoxedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    type Item = (L, &'a mut T);
+    type IntoIter = IterMut<'a, L, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
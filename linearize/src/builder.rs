@@ -0,0 +1,378 @@
+use {
+    crate::{map::iters::Iter, Linearize, StaticMap},
+    core::{
+        fmt::{Debug, Display, Formatter},
+        mem,
+    },
+};
+
+/// A builder for a [`StaticMap`] whose values are set one key at a time.
+///
+/// Unlike [`StaticMap::try_from_iter`], this type allows the caller to detect duplicate
+/// keys and to inspect which keys are still missing.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::cmp::Ordering;
+/// # use linearize::StaticMapBuilder;
+/// let mut builder = StaticMapBuilder::new();
+/// assert_eq!(builder.set(Ordering::Less, 1), None);
+/// assert_eq!(builder.set(Ordering::Greater, 2), None);
+/// assert!(builder.set_if_absent(Ordering::Less, 3).is_err());
+/// assert_eq!(builder.missing_keys().collect::<Vec<_>>(), [Ordering::Equal]);
+/// builder.set(Ordering::Equal, 0);
+/// let map = builder.finish().unwrap();
+/// assert_eq!(map[Ordering::Less], 1);
+/// ```
+pub struct StaticMapBuilder<L, T>
+where
+    L: Linearize,
+{
+    values: StaticMap<L, Option<T>>,
+    len: usize,
+}
+
+/// The error returned by [`StaticMapBuilder::finish`] if not all keys have been set.
+pub struct Incomplete<L>
+where
+    L: Linearize,
+{
+    missing: StaticMap<L, bool>,
+}
+
+/// An iterator over the keys that have not been set in a [`StaticMapBuilder`].
+///
+/// See [`StaticMapBuilder::missing_keys`].
+pub struct MissingKeys<'a, L, T>
+where
+    L: Linearize,
+{
+    iter: Iter<'a, L, Option<T>>,
+}
+
+impl<L, T> StaticMapBuilder<L, T>
+where
+    L: Linearize,
+{
+    /// Creates a new builder with no keys set.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            values: StaticMap::default(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of keys that have been set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no keys have been set.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns whether all keys have been set.
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.len == L::LENGTH
+    }
+
+    /// Returns whether `key` has been set.
+    #[inline]
+    pub fn contains_key(&self, key: &L) -> bool {
+        self.values[key].is_some()
+    }
+
+    /// Returns a reference to the value of `key` or `None` if it has not been set.
+    #[inline]
+    pub fn get(&self, key: &L) -> Option<&T> {
+        self.values[key].as_ref()
+    }
+
+    /// Returns a mutable reference to the value of `key` or `None` if it has not been set.
+    #[inline]
+    pub fn get_mut(&mut self, key: &L) -> Option<&mut T> {
+        self.values[key].as_mut()
+    }
+
+    /// Sets the value of `key`.
+    ///
+    /// If the key had already been set, the previous value is returned.
+    #[inline]
+    pub fn set(&mut self, key: L, value: T) -> Option<T> {
+        let prev = self.values[key].replace(value);
+        if prev.is_none() {
+            self.len += 1;
+        }
+        prev
+    }
+
+    /// Sets the value of `key` if it has not been set yet.
+    ///
+    /// On success, returns a mutable reference to the new value. Otherwise, the existing
+    /// value is left unchanged and `value` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::StaticMapBuilder;
+    /// let mut builder = StaticMapBuilder::new();
+    /// assert_eq!(builder.set_if_absent(true, 1), Ok(&mut 1));
+    /// assert_eq!(builder.set_if_absent(true, 2), Err(2));
+    /// assert_eq!(builder.get(&true), Some(&1));
+    /// ```
+    #[inline]
+    pub fn set_if_absent(&mut self, key: L, value: T) -> Result<&mut T, T> {
+        let slot = &mut self.values[key];
+        if slot.is_some() {
+            return Err(value);
+        }
+        self.len += 1;
+        Ok(slot.insert(value))
+    }
+
+    /// Removes the value of `key` and returns it.
+    #[inline]
+    pub fn remove(&mut self, key: &L) -> Option<T> {
+        let prev = self.values[key].take();
+        if prev.is_some() {
+            self.len -= 1;
+        }
+        prev
+    }
+
+    /// Returns an iterator over the keys that have not been set.
+    ///
+    /// Keys are ordered by their linearization.
+    #[inline]
+    pub fn missing_keys(&self) -> MissingKeys<'_, L, T> {
+        MissingKeys {
+            iter: self.values.iter(),
+        }
+    }
+
+    /// Converts this builder into a [`StaticMap`].
+    ///
+    /// If not all keys have been set, the values that have been set are dropped and an
+    /// error describing the missing keys is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::StaticMapBuilder;
+    /// let mut builder = StaticMapBuilder::new();
+    /// builder.set(true, 1);
+    /// let err = builder.finish().unwrap_err();
+    /// assert!(err.is_missing(&false));
+    /// assert!(!err.is_missing(&true));
+    /// ```
+    pub fn finish(self) -> Result<StaticMap<L, T>, Incomplete<L>> {
+        if !self.is_complete() {
+            return Err(Incomplete {
+                missing: self.values.map_values(|v| v.is_none()),
+            });
+        }
+        Ok(self.values.map_values(|v| v.unwrap()))
+    }
+
+    /// Removes all values from this builder.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.len = 0;
+    }
+
+    /// Returns the values that have been set so far and resets this builder.
+    #[inline]
+    pub fn take(&mut self) -> StaticMap<L, Option<T>> {
+        self.len = 0;
+        mem::take(&mut self.values)
+    }
+}
+
+impl<L, T> Default for StaticMapBuilder<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L, T> Clone for StaticMapBuilder<L, T>
+where
+    L: Linearize,
+    T: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<L, T> Debug for StaticMapBuilder<L, T>
+where
+    L: Linearize + Debug,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_map()
+            .entries(
+                self.values
+                    .iter()
+                    .filter_map(|(k, v)| Some((k, v.as_ref()?))),
+            )
+            .finish()
+    }
+}
+
+impl<L, T> From<StaticMap<L, T>> for StaticMapBuilder<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn from(map: StaticMap<L, T>) -> Self {
+        Self {
+            values: map.map_values(Some),
+            len: L::LENGTH,
+        }
+    }
+}
+
+impl<L, T> Extend<(L, T)> for StaticMapBuilder<L, T>
+where
+    L: Linearize,
+{
+    fn extend<I: IntoIterator<Item = (L, T)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.set(k, v);
+        }
+    }
+}
+
+impl<L> Incomplete<L>
+where
+    L: Linearize,
+{
+    /// Returns whether `key` was missing.
+    #[inline]
+    pub fn is_missing(&self, key: &L) -> bool {
+        self.missing[key]
+    }
+
+    /// Returns a map that contains `true` for all keys that were missing.
+    #[inline]
+    pub fn missing(&self) -> &StaticMap<L, bool> {
+        &self.missing
+    }
+}
+
+impl<L> Clone for Incomplete<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            missing: self.missing.clone(),
+        }
+    }
+}
+
+impl<L> PartialEq for Incomplete<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.missing == other.missing
+    }
+}
+
+impl<L> Eq for Incomplete<L> where L: Linearize {}
+
+impl<L> Debug for Incomplete<L>
+where
+    L: Linearize + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Incomplete")
+            .field("missing", &MissingList(self))
+            .finish()
+    }
+}
+
+struct MissingList<'a, L>(&'a Incomplete<L>)
+where
+    L: Linearize;
+
+impl<L> Debug for MissingList<'_, L>
+where
+    L: Linearize + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries(self.0.missing.iter().filter(|(_, m)| **m).map(|(k, _)| k))
+            .finish()
+    }
+}
+
+impl<L> Display for Incomplete<L>
+where
+    L: Linearize + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Missing keys: {:?}", MissingList(self))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<L> std::error::Error for Incomplete<L> where L: Linearize + Debug {}
+
+impl<L, T> Iterator for MissingKeys<'_, L, T>
+where
+    L: Linearize,
+{
+    type Item = L;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.find_map(|(k, v)| v.is_none().then_some(k))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<L, T> DoubleEndedIterator for MissingKeys<'_, L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.rfind(|(_, v)| v.is_none()).map(|(k, _)| k)
+    }
+}
+
+impl<L, T> Clone for MissingKeys<'_, L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+        }
+    }
+}
@@ -22,6 +22,16 @@ use {
 /// requires the values to implement `Copy`. This type exists due to limitations of the
 /// rust type system. It will be removed in a future version of this crate.
 #[repr(transparent)]
+#[cfg_attr(
+    feature = "zerocopy-0_8",
+    derive(
+        zerocopy_0_8::FromBytes,
+        zerocopy_0_8::IntoBytes,
+        zerocopy_0_8::Immutable,
+        zerocopy_0_8::KnownLayout,
+    ),
+    zerocopy(crate = "::zerocopy_0_8")
+)]
 pub struct StaticCopyMap<L, T>(
     /// The underlying `[T; L::LENGTH]`.
     pub <L as Linearize>::CopyStorage<T>,
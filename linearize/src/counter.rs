@@ -0,0 +1,485 @@
+use {
+    crate::{Linearize, LinearizeExt, StaticCopyMap, StaticMap},
+    core::{
+        cmp::Reverse,
+        fmt::{Debug, Display, Formatter},
+        hash::{Hash, Hasher},
+        ops::{
+            Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, Index, IndexMut, Sub,
+            SubAssign,
+        },
+    },
+};
+
+/// A multiset of values of type `L`.
+///
+/// This type stores a `u64` count for each value of `L`.
+///
+/// The operators on this type follow multiset semantics:
+///
+/// - `a + b` adds the counts of each key.
+/// - `a - b` subtracts the counts of each key, saturating at zero.
+/// - `a | b` is the union and takes the maximum of the counts of each key.
+/// - `a & b` is the intersection and takes the minimum of the counts of each key.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::cmp::Ordering;
+/// # use linearize::StaticCounter;
+/// let counter: StaticCounter<_> = [1, 3, 2, 3, 3].iter().map(|n| n.cmp(&2)).collect();
+/// assert_eq!(counter[Ordering::Greater], 3);
+/// assert_eq!(counter.total(), 5);
+/// let mut most_common = counter.most_common(2);
+/// assert_eq!(most_common.next(), Some((Ordering::Greater, 3)));
+/// assert_eq!(most_common.next(), Some((Ordering::Less, 1)));
+/// assert_eq!(most_common.next(), None);
+/// ```
+pub struct StaticCounter<L>
+where
+    L: Linearize,
+{
+    counts: StaticCopyMap<L, u64>,
+}
+
+/// An iterator over the most common keys of a [`StaticCounter`].
+///
+/// See [`StaticCounter::most_common`].
+pub struct MostCommon<'a, L>
+where
+    L: Linearize,
+{
+    counts: &'a StaticCopyMap<L, u64>,
+    last: Option<(u64, usize)>,
+    remaining: usize,
+}
+
+impl<L> StaticCounter<L>
+where
+    L: Linearize,
+{
+    /// Creates a new counter with all counts set to zero.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            counts: StaticCopyMap::default(),
+        }
+    }
+
+    /// Creates a counter from a map of counts.
+    #[inline]
+    pub fn from_counts(counts: StaticCopyMap<L, u64>) -> Self {
+        Self { counts }
+    }
+
+    /// Returns the counts of this counter.
+    #[inline]
+    pub fn counts(&self) -> &StaticCopyMap<L, u64> {
+        &self.counts
+    }
+
+    /// Returns a mutable reference to the counts of this counter.
+    #[inline]
+    pub fn counts_mut(&mut self) -> &mut StaticCopyMap<L, u64> {
+        &mut self.counts
+    }
+
+    /// Converts this counter into its counts.
+    #[inline]
+    pub fn into_counts(self) -> StaticCopyMap<L, u64> {
+        self.counts
+    }
+
+    /// Returns the count of `key`.
+    #[inline]
+    pub fn get(&self, key: &L) -> u64 {
+        self.counts[key]
+    }
+
+    /// Increments the count of `key` by one.
+    #[inline]
+    pub fn add(&mut self, key: L) {
+        self.add_n(key, 1);
+    }
+
+    /// Increments the count of `key` by `n`.
+    #[inline]
+    pub fn add_n(&mut self, key: L, n: u64) {
+        self.add_n_ref(&key, n);
+    }
+
+    #[inline]
+    fn add_n_ref(&mut self, key: &L, n: u64) {
+        self.counts[key] += n;
+    }
+
+    /// Decrements the count of `key` by `n`, saturating at zero.
+    #[inline]
+    pub fn remove_n(&mut self, key: &L, n: u64) {
+        let count = &mut self.counts[key];
+        *count = count.saturating_sub(n);
+    }
+
+    /// Sets all counts to zero.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.counts = StaticCopyMap::default();
+    }
+
+    /// Returns the sum of all counts.
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.counts.values().sum()
+    }
+
+    /// Returns whether all counts are zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.counts.values().all(|&c| c == 0)
+    }
+
+    /// Returns an iterator over the `k` keys with the highest counts.
+    ///
+    /// Keys with the same count are ordered by their linearization. Keys with a count
+    /// of zero are included if fewer than `k` keys have a non-zero count.
+    ///
+    /// The keys are selected lazily without copying the counts. Each step scans the
+    /// counts once, unless the next key has the same count as the previous one.
+    pub fn most_common(&self, k: usize) -> MostCommon<'_, L> {
+        MostCommon {
+            counts: &self.counts,
+            last: None,
+            remaining: k.min(L::LENGTH),
+        }
+    }
+
+    /// Returns the relative frequency of each key.
+    ///
+    /// If all counts are zero, all frequencies are zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::StaticCounter;
+    /// let counter: StaticCounter<bool> = [true, false, true, true].into_iter().collect();
+    /// let freq = counter.frequencies();
+    /// assert_eq!(freq[true], 0.75);
+    /// assert_eq!(freq[false], 0.25);
+    /// ```
+    pub fn frequencies(&self) -> StaticMap<L, f64> {
+        let total = self.total();
+        self.counts.into_static_map().map_values(|c| match total {
+            0 => 0.0,
+            _ => c as f64 / total as f64,
+        })
+    }
+
+    /// Returns the union of the two multisets.
+    ///
+    /// The count of each key is the maximum of its counts in `self` and `other`.
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        self.zip_with(other, u64::max)
+    }
+
+    /// Returns the intersection of the two multisets.
+    ///
+    /// The count of each key is the minimum of its counts in `self` and `other`.
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.zip_with(other, u64::min)
+    }
+
+    /// Returns the difference of the two multisets.
+    ///
+    /// The count of each key is its count in `self` minus its count in `other`, saturating
+    /// at zero.
+    #[inline]
+    pub fn saturating_sub(&self, other: &Self) -> Self {
+        self.zip_with(other, u64::saturating_sub)
+    }
+
+    fn zip_with(&self, other: &Self, mut f: impl FnMut(u64, u64) -> u64) -> Self {
+        Self {
+            counts: StaticCopyMap::from_fn(|l: L| {
+                let l = l.linearized();
+                f(self.counts[l], other.counts[l])
+            }),
+        }
+    }
+}
+
+impl<L> Copy for StaticCounter<L> where L: Linearize {}
+
+impl<L> Clone for StaticCounter<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L> Default for StaticCounter<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L> PartialEq for StaticCounter<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.counts == other.counts
+    }
+}
+
+impl<L> Eq for StaticCounter<L> where L: Linearize {}
+
+impl<L> Hash for StaticCounter<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.counts.hash(state);
+    }
+}
+
+impl<L> Debug for StaticCounter<L>
+where
+    L: Linearize + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.counts, f)
+    }
+}
+
+/// Formats the keys with non-zero counts, most common first.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::cmp::Ordering;
+/// # use linearize::StaticCounter;
+/// let counter: StaticCounter<_> = [Ordering::Less, Ordering::Greater, Ordering::Greater]
+///     .into_iter()
+///     .collect();
+/// assert_eq!(counter.to_string(), "{Greater: 2, Less: 1}");
+/// ```
+impl<L> Display for StaticCounter<L>
+where
+    L: Linearize + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let counts: &[u64] = &self.counts;
+        let mut order = StaticMap::<L, usize>::from_fn(|l| l.linearize());
+        order.sort_unstable_by_key(|&i| (Reverse(counts[i]), i));
+        f.write_str("{")?;
+        for (n, &i) in order.values().take_while(|&&i| counts[i] > 0).enumerate() {
+            if n > 0 {
+                f.write_str(", ")?;
+            }
+            let k = unsafe {
+                // SAFETY: order is a permutation of 0..L::LENGTH.
+                L::from_linear_unchecked(i)
+            };
+            write!(f, "{:?}: {}", k, counts[i])?;
+        }
+        f.write_str("}")
+    }
+}
+
+impl<L> Index<&'_ L> for StaticCounter<L>
+where
+    L: Linearize,
+{
+    type Output = u64;
+
+    #[inline]
+    fn index(&self, index: &L) -> &Self::Output {
+        &self.counts[index]
+    }
+}
+
+impl<L> Index<L> for StaticCounter<L>
+where
+    L: Linearize,
+{
+    type Output = u64;
+
+    #[inline]
+    fn index(&self, index: L) -> &Self::Output {
+        &self.counts[index]
+    }
+}
+
+impl<L> IndexMut<&'_ L> for StaticCounter<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn index_mut(&mut self, index: &L) -> &mut Self::Output {
+        &mut self.counts[index]
+    }
+}
+
+impl<L> IndexMut<L> for StaticCounter<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn index_mut(&mut self, index: L) -> &mut Self::Output {
+        &mut self.counts[index]
+    }
+}
+
+impl<L> FromIterator<L> for StaticCounter<L>
+where
+    L: Linearize,
+{
+    fn from_iter<I: IntoIterator<Item = L>>(iter: I) -> Self {
+        let mut res = Self::new();
+        res.extend(iter);
+        res
+    }
+}
+
+impl<'a, L> FromIterator<&'a L> for StaticCounter<L>
+where
+    L: Linearize,
+{
+    fn from_iter<I: IntoIterator<Item = &'a L>>(iter: I) -> Self {
+        let mut res = Self::new();
+        res.extend(iter);
+        res
+    }
+}
+
+impl<L> Extend<L> for StaticCounter<L>
+where
+    L: Linearize,
+{
+    fn extend<I: IntoIterator<Item = L>>(&mut self, iter: I) {
+        for k in iter {
+            self.add_n(k, 1);
+        }
+    }
+}
+
+impl<'a, L> Extend<&'a L> for StaticCounter<L>
+where
+    L: Linearize,
+{
+    fn extend<I: IntoIterator<Item = &'a L>>(&mut self, iter: I) {
+        for k in iter {
+            self.add_n_ref(k, 1);
+        }
+    }
+}
+
+impl<L> From<StaticCopyMap<L, u64>> for StaticCounter<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn from(counts: StaticCopyMap<L, u64>) -> Self {
+        Self { counts }
+    }
+}
+
+impl<L> From<StaticMap<L, u64>> for StaticCounter<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn from(counts: StaticMap<L, u64>) -> Self {
+        Self {
+            counts: counts.into_copy(),
+        }
+    }
+}
+
+macro_rules! impl_op {
+    ($op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident, $f:expr) => {
+        impl<L> $op for StaticCounter<L>
+        where
+            L: Linearize,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $op_fn(self, rhs: Self) -> Self::Output {
+                self.zip_with(&rhs, $f)
+            }
+        }
+
+        impl<L> $op_assign for StaticCounter<L>
+        where
+            L: Linearize,
+        {
+            #[inline]
+            fn $op_assign_fn(&mut self, rhs: Self) {
+                *self = self.zip_with(&rhs, $f);
+            }
+        }
+    };
+}
+
+impl_op!(Add, add, AddAssign, add_assign, |a, b| a + b);
+impl_op!(Sub, sub, SubAssign, sub_assign, u64::saturating_sub);
+impl_op!(BitOr, bitor, BitOrAssign, bitor_assign, u64::max);
+impl_op!(BitAnd, bitand, BitAndAssign, bitand_assign, u64::min);
+
+impl<L> Iterator for MostCommon<'_, L>
+where
+    L: Linearize,
+{
+    type Item = (L, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let counts: &[u64] = self.counts.as_ref();
+        let next = match self.last {
+            None => None,
+            Some((count, idx)) => counts[idx + 1..]
+                .iter()
+                .position(|&c| c == count)
+                .map(|pos| (count, idx + 1 + pos)),
+        };
+        let next = next.or_else(|| {
+            let below = |c: u64| self.last.is_none_or(|(count, _)| c < count);
+            let mut best: Option<(u64, usize)> = None;
+            for (idx, &c) in counts.iter().enumerate() {
+                if below(c) && best.is_none_or(|(count, _)| c > count) {
+                    best = Some((c, idx));
+                }
+            }
+            best
+        });
+        let (count, idx) = next?;
+        self.last = next;
+        let key = unsafe {
+            // SAFETY: idx is an index into the counts, so it is less than L::LENGTH.
+            L::from_linear_unchecked(idx)
+        };
+        Some((key, count))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<L> ExactSizeIterator for MostCommon<'_, L> where L: Linearize {}
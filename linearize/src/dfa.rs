@@ -0,0 +1,548 @@
+use {
+    crate::{Linearize, LinearizeExt, LinearizeProduct, Linearized, StaticMap, StaticMatrix},
+    core::fmt::{Debug, Display, Formatter, Write},
+};
+
+/// A deterministic finite automaton with states `S` and input events `E`.
+///
+/// Every state has exactly one transition for each event.
+///
+/// # Example
+///
+/// ```rust
+/// # use linearize::{Dfa, Linearize};
+/// #[derive(Linearize, Copy, Clone, Debug, PartialEq)]
+/// enum State {
+///     Closed,
+///     Open,
+/// }
+///
+/// #[derive(Linearize, Copy, Clone, Debug, PartialEq)]
+/// enum Event {
+///     Push,
+///     Pull,
+/// }
+///
+/// let dfa = Dfa::from_fn(
+///     State::Closed,
+///     |_, e| match e {
+///         Event::Push => State::Open,
+///         Event::Pull => State::Closed,
+///     },
+///     |s| s == State::Open,
+/// );
+/// assert_eq!(dfa.run([Event::Push, Event::Push]), State::Open);
+/// assert!(!dfa.accepts([Event::Push, Event::Pull]));
+/// ```
+pub struct Dfa<S, E>
+where
+    S: Linearize,
+    E: Linearize,
+{
+    start: Linearized<S>,
+    transitions: StaticMatrix<S, E, Linearized<S>>,
+    accepting: StaticMap<S, bool>,
+}
+
+/// A [`Display`] implementation that formats a [`Dfa`] in the Graphviz DOT language.
+///
+/// See [`Dfa::dot`].
+pub struct Dot<'a, S, E>
+where
+    S: Linearize,
+    E: Linearize,
+{
+    dfa: &'a Dfa<S, E>,
+}
+
+impl<S, E> Dfa<S, E>
+where
+    S: Linearize,
+    E: Linearize,
+{
+    /// Creates an automaton from a transition table.
+    #[inline]
+    pub fn new(
+        start: S,
+        transitions: StaticMap<S, StaticMap<E, S>>,
+        accepting: StaticMap<S, bool>,
+    ) -> Self {
+        Self {
+            start: start.linearized(),
+            transitions: StaticMatrix(
+                transitions.map_values(|row| row.map_values(|s| s.linearized())),
+            ),
+            accepting,
+        }
+    }
+
+    /// Creates an automaton from a transition function and an acceptance predicate.
+    #[inline]
+    pub fn from_fn(
+        start: S,
+        mut transition: impl FnMut(S, E) -> S,
+        accepting: impl FnMut(S) -> bool,
+    ) -> Self {
+        Self {
+            start: start.linearized(),
+            transitions: StaticMatrix::from_fn(|s, e| transition(s, e).linearized()),
+            accepting: StaticMap::from_fn(accepting),
+        }
+    }
+
+    /// Returns the start state.
+    #[inline]
+    pub fn start(&self) -> S {
+        self.start.delinearize()
+    }
+
+    /// Sets the start state.
+    #[inline]
+    pub fn set_start(&mut self, start: &S) {
+        self.start = start.linearized();
+    }
+
+    /// Returns the state reached from `state` by `event`.
+    #[inline]
+    pub fn step(&self, state: &S, event: &E) -> S {
+        self.transitions[(state, event)].delinearize()
+    }
+
+    /// Sets the state reached from `state` by `event`.
+    #[inline]
+    pub fn set_transition(&mut self, state: &S, event: &E, target: &S) {
+        self.transitions[(state, event)] = target.linearized();
+    }
+
+    /// Returns the state reached from `state` after processing all `events`.
+    pub fn run_from(&self, state: &S, events: impl IntoIterator<Item = E>) -> S {
+        let mut state = state.linearized();
+        for event in events {
+            state = self.transitions.0[state][event];
+        }
+        state.delinearize()
+    }
+
+    /// Returns the state reached from the start state after processing all `events`.
+    #[inline]
+    pub fn run(&self, events: impl IntoIterator<Item = E>) -> S {
+        self.run_from(&self.start(), events)
+    }
+
+    /// Returns whether the state reached after processing all `events` is accepting.
+    #[inline]
+    pub fn accepts(&self, events: impl IntoIterator<Item = E>) -> bool {
+        self.is_accepting(&self.run(events))
+    }
+
+    /// Returns whether `state` is accepting.
+    #[inline]
+    pub fn is_accepting(&self, state: &S) -> bool {
+        self.accepting[state]
+    }
+
+    /// Sets whether `state` is accepting.
+    #[inline]
+    pub fn set_accepting(&mut self, state: &S, accepting: bool) {
+        self.accepting[state] = accepting;
+    }
+
+    /// Returns the set of accepting states.
+    #[inline]
+    pub fn accepting(&self) -> &StaticMap<S, bool> {
+        &self.accepting
+    }
+
+    /// Returns the set of states that are reachable from the start state.
+    pub fn reachable_states(&self) -> StaticMap<S, bool> {
+        let mut reachable = StaticMap::<S, bool>::default();
+        let mut stack = StaticMap::<S, Linearized<S>>::from_fn(|_| self.start);
+        let stack: &mut [_] = &mut stack;
+        let mut len = 1;
+        reachable[self.start] = true;
+        while len > 0 {
+            len -= 1;
+            let state = stack[len];
+            for &next in self.transitions.0[state].values() {
+                if !reachable[next] {
+                    reachable[next] = true;
+                    // Each state is pushed at most once.
+                    stack[len] = next;
+                    len += 1;
+                }
+            }
+        }
+        reachable
+    }
+
+    /// Replaces all states that are not reachable from the start state by non-accepting
+    /// states whose transitions all lead back to themselves.
+    ///
+    /// This does not change the language of the automaton.
+    pub fn prune_unreachable(&mut self) {
+        let reachable = self.reachable_states();
+        for (state, &reachable) in reachable.iter() {
+            if !reachable {
+                let state = state.linearized();
+                self.accepting[state] = false;
+                self.transitions.0[state].fill(state);
+            }
+        }
+    }
+
+    /// Partitions the reachable states into classes of equivalent states.
+    ///
+    /// Two states are equivalent if they accept the same sequences of events. Returns
+    /// the class of each reachable state and `None` for unreachable states. Classes are
+    /// numbered from `0` in the order of their first state. The number of classes is
+    /// the number of states of the minimal automaton.
+    ///
+    /// This function uses Hopcroft's algorithm.
+    pub fn equivalence_classes(&self) -> StaticMap<S, Option<usize>> {
+        let reachable = self.reachable_states();
+        // block[s] is the block of state s. Blocks are identified by numbers less than
+        // S::LENGTH since there are at most S::LENGTH non-empty blocks. Arrays indexed by
+        // block numbers are therefore backed by maps keyed by S.
+        let mut block = StaticMap::<S, usize>::default();
+        let mut block_size = StaticMap::<S, usize>::default();
+        let block_size: &mut [_] = &mut block_size;
+        let mut num_blocks = 0;
+        for accepting in [false, true] {
+            let mut size = 0;
+            for (s, _) in reachable
+                .iter()
+                .filter(|(s, r)| **r && self.accepting[s] == accepting)
+            {
+                block[s] = num_blocks;
+                size += 1;
+            }
+            if size > 0 {
+                block_size[num_blocks] = size;
+                num_blocks += 1;
+            }
+        }
+        // The worklist contains each block at most once.
+        let mut in_worklist = StaticMap::<S, bool>::default();
+        let in_worklist: &mut [_] = &mut in_worklist;
+        let mut worklist = StaticMap::<S, usize>::default();
+        let worklist: &mut [_] = &mut worklist;
+        for (b, w) in worklist[..num_blocks].iter_mut().enumerate() {
+            in_worklist[b] = true;
+            *w = b;
+        }
+        let mut worklist_len = num_blocks;
+        let mut in_x = StaticMap::<S, bool>::default();
+        let mut x_count = StaticMap::<S, usize>::default();
+        let x_count: &mut [_] = &mut x_count;
+        while worklist_len > 0 {
+            worklist_len -= 1;
+            let splitter = worklist[worklist_len];
+            in_worklist[splitter] = false;
+            for event in E::variants() {
+                // X is the set of reachable states that move into the splitter on event.
+                x_count.fill(0);
+                for (s, x) in in_x.iter_mut() {
+                    let s = s.linearized();
+                    *x = reachable[s] && block[self.transitions.0[s][&event]] == splitter;
+                    if *x {
+                        x_count[block[s]] += 1;
+                    }
+                }
+                // Blocks created in this loop are subsets of X and are not split again.
+                let old_blocks = num_blocks;
+                for y in 0..old_blocks {
+                    let count = x_count[y];
+                    if count == 0 || count == block_size[y] {
+                        continue;
+                    }
+                    // Split Y into Y \ X, which keeps the number of Y, and Y ∩ X, which
+                    // becomes a new block.
+                    let new = num_blocks;
+                    num_blocks += 1;
+                    for (s, b) in block.iter_mut() {
+                        if *b == y && in_x[s] {
+                            *b = new;
+                        }
+                    }
+                    block_size[new] = count;
+                    block_size[y] -= count;
+                    // If Y is already in the worklist, Y \ X remains in the worklist and
+                    // Y ∩ X must be added. Otherwise it suffices to add the smaller half.
+                    let add = if in_worklist[y] || count <= block_size[y] {
+                        new
+                    } else {
+                        y
+                    };
+                    if !in_worklist[add] {
+                        in_worklist[add] = true;
+                        worklist[worklist_len] = add;
+                        worklist_len += 1;
+                    }
+                }
+            }
+        }
+        let mut class = StaticMap::<S, Option<usize>>::default();
+        let class: &mut [_] = &mut class;
+        let mut num_classes = 0;
+        let mut res = StaticMap::<S, Option<usize>>::default();
+        for (s, r) in res.iter_mut() {
+            if reachable[&s] {
+                let b = block[s];
+                let c = class[b].get_or_insert_with(|| {
+                    num_classes += 1;
+                    num_classes - 1
+                });
+                *r = Some(*c);
+            }
+        }
+        res
+    }
+
+    /// Returns an equivalent automaton with the minimal number of reachable states.
+    ///
+    /// Each class of [equivalent states](Self::equivalence_classes) is represented by
+    /// its first state. All transitions of reachable states lead to such a
+    /// representative. All other states are not reachable from the start state.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::Dfa;
+    /// // Accepts sequences with an even number of `true` events.
+    /// let dfa = Dfa::from_fn(0u8, |s, e: bool| (s % 4 + e as u8) % 4, |s| s % 2 == 0);
+    /// assert_eq!(dfa.reachable_states().values().filter(|r| **r).count(), 4);
+    /// let min = dfa.minimize();
+    /// assert_eq!(min.reachable_states().values().filter(|r| **r).count(), 2);
+    /// assert!(min.accepts([true, false, true]));
+    /// ```
+    pub fn minimize(&self) -> Self {
+        let classes = self.equivalence_classes();
+        let mut representative = StaticMap::<S, Option<Linearized<S>>>::default();
+        let representative: &mut [_] = &mut representative;
+        for (s, c) in classes.iter() {
+            if let Some(c) = *c {
+                representative[c].get_or_insert(s.linearized());
+            }
+        }
+        let rep = |s: Linearized<S>| match classes[s] {
+            Some(c) => representative[c].unwrap(),
+            None => s,
+        };
+        Self {
+            start: rep(self.start),
+            transitions: StaticMatrix(
+                self.transitions
+                    .0
+                    .clone()
+                    .map_values(|row| row.map_values(rep)),
+            ),
+            accepting: self.accepting.clone(),
+        }
+    }
+
+    /// Runs this automaton and `other` in parallel.
+    ///
+    /// The states of the result are pairs of states of the two automata. A pair is
+    /// accepting if `accept` returns `true` for the acceptance of its components.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::{Dfa, Linearize};
+    /// #[derive(Linearize)]
+    /// struct Pair(u8, bool);
+    ///
+    /// // Accepts if the number of events is divisible by 3.
+    /// let a = Dfa::from_fn(0u8, |s, _: bool| (s % 3 + 1) % 3, |s| s == 0);
+    /// // Accepts if the last event was `true`.
+    /// let b = Dfa::from_fn(false, |_, e| e, |s| s);
+    /// let both = a.product::<bool, Pair>(&b, |a, b| a && b);
+    /// assert!(both.accepts([false, false, true]));
+    /// assert!(!both.accepts([false, true]));
+    /// assert!(!both.accepts([false, true, false]));
+    /// ```
+    pub fn product<S2, P>(
+        &self,
+        other: &Dfa<S2, E>,
+        mut accept: impl FnMut(bool, bool) -> bool,
+    ) -> Dfa<P, E>
+    where
+        S2: Linearize,
+        P: LinearizeProduct<First = S, Second = S2>,
+    {
+        const {
+            assert!(P::LENGTH == S::LENGTH * S2::LENGTH);
+        }
+        let pair = |a: Linearized<S>, b: Linearized<S2>| unsafe {
+            // SAFETY: a < S::LENGTH and b < S2::LENGTH. Therefore
+            // a * S2::LENGTH + b < S::LENGTH * S2::LENGTH = P::LENGTH.
+            Linearized::<P>::new_unchecked(a.get() * S2::LENGTH + b.get())
+        };
+        let split = |p: usize| unsafe {
+            // SAFETY: p < P::LENGTH = S::LENGTH * S2::LENGTH.
+            (
+                Linearized::<S>::new_unchecked(p / S2::LENGTH),
+                Linearized::<S2>::new_unchecked(p % S2::LENGTH),
+            )
+        };
+        Dfa {
+            start: pair(self.start, other.start),
+            transitions: StaticMatrix::from_fn(|p: P, e: E| {
+                let (a, b) = split(p.linearize());
+                let e = e.linearized();
+                pair(self.transitions.0[a][e], other.transitions.0[b][e])
+            }),
+            accepting: StaticMap::from_fn(|p: P| {
+                let (a, b) = split(p.linearize());
+                accept(self.accepting[a], other.accepting[b])
+            }),
+        }
+    }
+
+    /// Returns a [`Display`] implementation that formats this automaton in the Graphviz
+    /// DOT language.
+    ///
+    /// Only states that are reachable from the start state are included. States and
+    /// events are labeled with their [`Debug`] output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::Dfa;
+    /// let dfa = Dfa::from_fn(false, |_, e: bool| e, |s| s);
+    /// assert_eq!(
+    ///     dfa.dot().to_string(),
+    ///     "digraph {\n\
+    ///     \x20   start [shape=point];\n\
+    ///     \x20   start -> 0;\n\
+    ///     \x20   0 [label=\"false\", shape=circle];\n\
+    ///     \x20   1 [label=\"true\", shape=doublecircle];\n\
+    ///     \x20   0 -> 0 [label=\"false\"];\n\
+    ///     \x20   0 -> 1 [label=\"true\"];\n\
+    ///     \x20   1 -> 0 [label=\"false\"];\n\
+    ///     \x20   1 -> 1 [label=\"true\"];\n\
+    ///     }\n",
+    /// );
+    /// ```
+    #[inline]
+    pub fn dot(&self) -> Dot<'_, S, E> {
+        Dot { dfa: self }
+    }
+}
+
+impl<S, E> Clone for Dfa<S, E>
+where
+    S: Linearize,
+    E: Linearize,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            start: self.start,
+            transitions: self.transitions.clone(),
+            accepting: self.accepting.clone(),
+        }
+    }
+}
+
+impl<S, E> PartialEq for Dfa<S, E>
+where
+    S: Linearize,
+    E: Linearize,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start
+            && self.transitions == other.transitions
+            && self.accepting == other.accepting
+    }
+}
+
+impl<S, E> Eq for Dfa<S, E>
+where
+    S: Linearize,
+    E: Linearize,
+{
+}
+
+impl<S, E> Debug for Dfa<S, E>
+where
+    S: Linearize + Debug,
+    E: Linearize + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Dfa")
+            .field("start", &self.start.delinearize())
+            .field(
+                "transitions",
+                &StaticMatrix::from_fn(|s, e| self.step(&s, &e)),
+            )
+            .field("accepting", &self.accepting)
+            .finish()
+    }
+}
+
+impl<S, E> Display for Dot<'_, S, E>
+where
+    S: Linearize + Debug,
+    E: Linearize + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        struct Escaped<T>(T);
+        impl<T: Debug> Display for Escaped<T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                struct Writer<'a, 'b>(&'a mut Formatter<'b>);
+                impl Write for Writer<'_, '_> {
+                    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                        for c in s.chars() {
+                            match c {
+                                '"' => self.0.write_str("\\\"")?,
+                                '\\' => self.0.write_str("\\\\")?,
+                                _ => self.0.write_char(c)?,
+                            }
+                        }
+                        Ok(())
+                    }
+                }
+                write!(Writer(f), "{:?}", self.0)
+            }
+        }
+        let dfa = self.dfa;
+        let reachable = dfa.reachable_states();
+        writeln!(f, "digraph {{")?;
+        writeln!(f, "    start [shape=point];")?;
+        writeln!(f, "    start -> {};", dfa.start.get())?;
+        for (s, _) in reachable.iter().filter(|(_, r)| **r) {
+            let shape = match dfa.accepting[&s] {
+                true => "doublecircle",
+                false => "circle",
+            };
+            writeln!(
+                f,
+                "    {} [label=\"{}\", shape={}];",
+                s.linearize(),
+                Escaped(&s),
+                shape
+            )?;
+        }
+        for (s, _) in reachable.iter().filter(|(_, r)| **r) {
+            let row = &dfa.transitions.0[&s];
+            for target in S::variants() {
+                let target = target.linearized();
+                let mut events = E::variants().filter(|e| row[e] == target).peekable();
+                if events.peek().is_none() {
+                    continue;
+                }
+                write!(f, "    {} -> {} [label=\"", s.linearize(), target.get())?;
+                for (i, e) in events.enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", Escaped(&e))?;
+                }
+                writeln!(f, "\"];")?;
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
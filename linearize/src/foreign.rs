@@ -1,5 +1,7 @@
 #[cfg(feature = "arbitrary-1")]
 mod arbitrary_1;
+#[cfg(feature = "borsh-1")]
+mod borsh_1;
 #[cfg(feature = "bytemuck-1")]
 mod bytemuck_1;
 #[cfg(feature = "rand-0_8")]
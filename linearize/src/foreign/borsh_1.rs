@@ -0,0 +1,108 @@
+use {
+    crate::{Linearize, StaticCopyMap, StaticMap},
+    borsh_1::{
+        io::{Read, Result, Write},
+        BorshDeserialize, BorshSerialize,
+    },
+};
+#[cfg(feature = "borsh-1-schema")]
+use {
+    alloc::{collections::BTreeMap, format},
+    borsh_1::{
+        schema::{add_definition, Declaration, Definition},
+        BorshSchema,
+    },
+    core::any::type_name,
+};
+
+impl<L, T> BorshSerialize for StaticMap<L, T>
+where
+    L: Linearize,
+    T: BorshSerialize,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        for v in self.values() {
+            v.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<L, T> BorshDeserialize for StaticMap<L, T>
+where
+    L: Linearize,
+    T: BorshDeserialize,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut res = StaticMap::<L, Option<T>>::default();
+        for v in res.values_mut() {
+            *v = Some(T::deserialize_reader(reader)?);
+        }
+        Ok(res.map_values(|v| unsafe {
+            // SAFETY: We've just set all values to Some.
+            v.unwrap_unchecked()
+        }))
+    }
+}
+
+impl<L, T> BorshSerialize for StaticCopyMap<L, T>
+where
+    L: Linearize,
+    T: Copy + BorshSerialize,
+{
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_static_map().serialize(writer)
+    }
+}
+
+impl<L, T> BorshDeserialize for StaticCopyMap<L, T>
+where
+    L: Linearize,
+    T: Copy + BorshDeserialize,
+{
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        StaticMap::deserialize_reader(reader).map(StaticMap::into_copy)
+    }
+}
+
+#[cfg(feature = "borsh-1-schema")]
+impl<L, T> BorshSchema for StaticMap<L, T>
+where
+    L: Linearize,
+    T: BorshSchema,
+{
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        let length = L::LENGTH as u64;
+        let definition = Definition::Sequence {
+            length_width: Definition::ARRAY_LENGTH_WIDTH,
+            length_range: length..=length,
+            elements: T::declaration(),
+        };
+        add_definition(Self::declaration(), definition, definitions);
+        T::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> Declaration {
+        // The keys are not part of the encoding, so the key type only needs a name.
+        format!("StaticMap<{}, {}>", type_name::<L>(), T::declaration())
+    }
+}
+
+#[cfg(feature = "borsh-1-schema")]
+impl<L, T> BorshSchema for StaticCopyMap<L, T>
+where
+    L: Linearize,
+    T: Copy + BorshSchema,
+{
+    #[inline]
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        StaticMap::<L, T>::add_definitions_recursively(definitions);
+    }
+
+    #[inline]
+    fn declaration() -> Declaration {
+        StaticMap::<L, T>::declaration()
+    }
+}
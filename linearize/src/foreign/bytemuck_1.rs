@@ -24,6 +24,18 @@ where
 {
 }
 
+// `Pod` and `CheckedBitPattern` cannot be implemented for `StaticCopyMap` because bytemuck
+// implements `NoUninit` and `AnyBitPattern` for all `Pod` types and `CheckedBitPattern`
+// for all `AnyBitPattern` types. These blanket impls would conflict with the impls above.
+
+// `NoUninit` and `AnyBitPattern` require `Copy`, which `StaticMap` never implements.
+unsafe impl<L, T> Zeroable for StaticMap<L, T>
+where
+    L: Linearize + ?Sized,
+    T: Zeroable,
+{
+}
+
 unsafe impl<L, T> TransparentWrapper<L::CopyStorage<T>> for StaticCopyMap<L, T>
 where
     L: Linearize + ?Sized,
@@ -4,23 +4,78 @@
 //! `HashMap<L, T>`. If a key is missing during deserialization, the entire operation
 //! fails.
 //!
-//! This behavior can be adjusted by using the [`skip_none`] and [`use_default`] modules.
+//! If a key occurs multiple times, the last value is used. The [`strict`] module rejects
+//! duplicate keys instead.
+//!
+//! This behavior can be adjusted by using the [`skip_none`], [`use_default`], and
+//! [`strict`] modules. The [`as_tuple`] and [`as_seq`] modules serialize only the values
+//! in the order of their keys, which is more compact in binary formats. The
+//! [`index_keys`] module uses the linear index of each key as the key and the
+//! [`display_keys`] module uses the `Display` representation of each key as the key.
+//! The [`migrate`] module deserializes maps written with an older version of the key
+//! type.
+//!
+//! [`StaticCounter`](crate::StaticCounter) uses the wire format of `HashMap<L, u64>`.
+//! Keys with a count of zero are skipped during serialization and missing keys are
+//! treated as zero during deserialization.
+//!
+//! [`Linearized<L>`](crate::Linearized) uses the wire format of `usize`. The
+//! deserializer fails if the index is not less than `L::LENGTH`. The [`index_keys`]
+//! module uses this format for the keys of a `StaticMap`.
+//!
+//! [`StaticMapPatch`](crate::StaticMapPatch) uses the wire format of `HashMap<L, T>`
+//! that only contains the changed keys, the same format as [`skip_none`].
 
 mod default {
     use {
-        crate::{Linearize, LinearizeExt, StaticCopyMap, StaticMap},
+        crate::{Linearize, Linearized, StaticCopyMap, StaticCounter, StaticMap, StaticMapPatch},
         core::{
             fmt::{Debug, Display, Formatter},
             marker::PhantomData,
             ops::Deref,
         },
         serde_1::{
-            de::{Error, MapAccess, Visitor},
+            de::{Error, MapAccess, Unexpected, Visitor},
             ser::SerializeMap,
             Deserialize, Deserializer, Serialize, Serializer,
         },
     };
 
+    impl<L> Serialize for Linearized<L>
+    where
+        L: ?Sized,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.get().serialize(serializer)
+        }
+    }
+
+    impl<'de, L> Deserialize<'de> for Linearized<L>
+    where
+        L: Linearize,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let index = usize::deserialize(deserializer)?;
+            Linearized::from_index(index).ok_or_else(|| {
+                Error::invalid_value(Unexpected::Unsigned(index as u64), &IndexBound(L::LENGTH))
+            })
+        }
+    }
+
+    struct IndexBound(usize);
+
+    impl serde_1::de::Expected for IndexBound {
+        fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+            write!(f, "an index less than {}", self.0)
+        }
+    }
+
     impl<L, T> Serialize for StaticMap<L, T>
     where
         L: Linearize + Serialize,
@@ -74,23 +129,54 @@ mod default {
             while let Some((k, v)) = map.next_entry::<L, T>()? {
                 res[k] = Some(v);
             }
-            for (idx, v) in res.deref().iter().enumerate() {
-                if v.is_none() {
-                    return Err(Error::custom(MissingKey(L::from_linear(idx).unwrap())));
-                }
+            if res.values().any(|v| v.is_none()) {
+                return Err(Error::custom(MissingKeys(&res)));
             }
             Ok(res.map_values(|v| unsafe {
-                // SAFETY: We just checked that v is Some.
+                // SAFETY: We just checked that all values are Some.
                 v.unwrap_unchecked()
             }))
         }
     }
 
-    struct MissingKey<L>(L);
-    impl<L: Debug> Display for MissingKey<L> {
+    pub(super) struct MissingKeys<'a, L, T>(pub(super) &'a StaticMap<L, Option<T>>)
+    where
+        L: Linearize;
+
+    impl<L, T> Display for MissingKeys<'_, L, T>
+    where
+        L: Linearize + Debug,
+    {
         fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-            write!(f, "Missing key {:?} in static map", self.0)
+            fmt_missing_keys(f, self.0, |f, k| write!(f, "{:?}", k))
+        }
+    }
+
+    /// Lists the keys of `map` that have no value.
+    pub(super) fn fmt_missing_keys<L, T>(
+        f: &mut Formatter<'_>,
+        map: &StaticMap<L, Option<T>>,
+        mut fmt_key: impl FnMut(&mut Formatter<'_>, &L) -> core::fmt::Result,
+    ) -> core::fmt::Result
+    where
+        L: Linearize,
+    {
+        let mut missing = map.iter().filter(|(_, v)| v.is_none()).map(|(k, _)| k);
+        let Some(first) = missing.next() else {
+            return Ok(());
+        };
+        let Some(second) = missing.next() else {
+            f.write_str("Missing key ")?;
+            fmt_key(f, &first)?;
+            return f.write_str(" in static map");
+        };
+        f.write_str("Missing keys [")?;
+        fmt_key(f, &first)?;
+        for k in [second].into_iter().chain(missing) {
+            f.write_str(", ")?;
+            fmt_key(f, &k)?;
         }
+        f.write_str("] in static map")
     }
 
     impl<L, T> Serialize for StaticCopyMap<L, T>
@@ -118,6 +204,65 @@ mod default {
             StaticMap::deserialize(deserializer).map(|v| v.into_copy())
         }
     }
+
+    impl<L> Serialize for StaticCounter<L>
+    where
+        L: Linearize + Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let counts = self.counts();
+            let len = counts.values().filter(|&&c| c > 0).count();
+            let mut map = serializer.serialize_map(Some(len))?;
+            for (k, &v) in counts {
+                if v > 0 {
+                    map.serialize_entry(&k, &v)?;
+                }
+            }
+            map.end()
+        }
+    }
+
+    impl<'de, L> Deserialize<'de> for StaticCounter<L>
+    where
+        L: Linearize + Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            super::use_default::deserialize::<L, u64, D, StaticMap<L, u64>>(deserializer)
+                .map(Self::from)
+        }
+    }
+
+    impl<L, T> Serialize for StaticMapPatch<L, T>
+    where
+        L: Linearize + Serialize,
+        T: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            super::skip_none::serialize(self.changes(), serializer)
+        }
+    }
+
+    impl<'de, L, T> Deserialize<'de> for StaticMapPatch<L, T>
+    where
+        L: Linearize + Deserialize<'de>,
+        T: Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            super::skip_none::deserialize(deserializer)
+        }
+    }
 }
 
 /// A de/serialize implementation for `StaticMap<L, Option<T>>` that skips `None` values.
@@ -145,7 +290,7 @@ pub mod skip_none {
         crate::{Linearize, StaticMap},
         core::{fmt::Formatter, marker::PhantomData},
         serde_1::{
-            de::{MapAccess, Visitor},
+            de::{DeserializeSeed, IgnoredAny, MapAccess, Visitor},
             ser::SerializeMap,
             Deserialize, Deserializer, Serialize, Serializer,
         },
@@ -178,17 +323,25 @@ pub mod skip_none {
         O: From<StaticMap<L, Option<T>>>,
     {
         deserializer
-            .deserialize_map(V(PhantomData))
+            .deserialize_map(V(PhantomData::<L>, PhantomData))
             .map(|v| v.into())
     }
 
-    struct V<L, T>(PhantomData<fn() -> StaticMap<L, T>>)
+    /// A visitor that deserializes the keys with `S` and leaves missing values as `None`.
+    ///
+    /// If `S` returns `None` for a key, the key and its value are skipped.
+    pub(super) struct V<S, L, T>(
+        pub(super) S,
+        pub(super) PhantomData<fn() -> StaticMap<L, T>>,
+    )
     where
         L: Linearize;
 
-    impl<'de, L, T> Visitor<'de> for V<L, T>
+    impl<'de, S, L, T> Visitor<'de> for V<S, L, T>
     where
-        L: Deserialize<'de> + Linearize,
+        S: DeserializeSeed<'de> + Copy,
+        S::Value: Into<Option<L>>,
+        L: Linearize,
         T: Deserialize<'de>,
     {
         type Value = StaticMap<L, Option<T>>;
@@ -202,8 +355,13 @@ pub mod skip_none {
             A: MapAccess<'de>,
         {
             let mut res = StaticMap::<L, Option<T>>::default();
-            while let Some((k, v)) = map.next_entry::<L, T>()? {
-                res[k] = Some(v);
+            while let Some(k) = map.next_key_seed(self.0)? {
+                match k.into() {
+                    Some(k) => res[k] = Some(map.next_value()?),
+                    None => {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                }
             }
             Ok(res)
         }
@@ -226,12 +384,10 @@ pub mod skip_none {
 /// ```
 pub mod use_default {
     use {
+        super::skip_none::V,
         crate::{Linearize, StaticMap},
-        core::{fmt::Formatter, marker::PhantomData},
-        serde_1::{
-            de::{MapAccess, Visitor},
-            Deserialize, Deserializer,
-        },
+        core::marker::PhantomData,
+        serde_1::{Deserialize, Deserializer},
     };
 
     pub fn deserialize<'de, L, T, D, O>(deserializer: D) -> Result<O, D::Error>
@@ -242,18 +398,156 @@ pub mod use_default {
         O: From<StaticMap<L, T>>,
     {
         deserializer
-            .deserialize_map(V(PhantomData))
+            .deserialize_map(V(PhantomData::<L>, PhantomData))
+            .map(|v| v.map_values(|v| v.unwrap_or_default()).into())
+    }
+}
+
+/// A de/serialize implementation for `StaticMap<L, T>` that rejects duplicate keys.
+///
+/// The serializer is the same as the default serializer.
+///
+/// The deserializer fails if a key occurs multiple times or if any key is missing. All
+/// missing keys are reported in a single error.
+///
+/// To use strict deserialization wherever a map type is used, without annotating each
+/// field, wrap the map in [`Strict`](strict::Strict).
+///
+/// # Example
+///
+/// ```rust
+/// # use serde_1::{Serialize, Deserialize};
+/// # use linearize::StaticMap;
+/// #[derive(Serialize, Deserialize)]
+/// # #[serde(crate = "serde_1")]
+/// struct X {
+///     #[serde(with = "linearize::serde_1::strict")]
+///     map: StaticMap<bool, u8>,
+/// }
+/// ```
+pub mod strict {
+    use {
+        super::default::MissingKeys,
+        crate::{Linearize, LinearizeExt, StaticCopyMap, StaticMap},
+        core::{
+            fmt::{Debug, Display, Formatter},
+            marker::PhantomData,
+            ops::{Deref, DerefMut},
+        },
+        serde_1::{
+            de::{Error, MapAccess, Visitor},
+            Deserialize, Deserializer, Serialize, Serializer,
+        },
+    };
+
+    pub fn serialize<L, T, S>(
+        static_map: &StaticMap<L, T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        L: Linearize + Serialize,
+        T: Serialize,
+        S: Serializer,
+    {
+        static_map.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, L, T, D, O>(deserializer: D) -> Result<O, D::Error>
+    where
+        L: Deserialize<'de> + Linearize + Debug,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+        O: From<StaticMap<L, T>>,
+    {
+        deserializer
+            .deserialize_map(StrictVisitor(PhantomData))
             .map(|v| v.into())
     }
 
-    struct V<L, T>(PhantomData<fn() -> StaticMap<L, T>>)
+    /// A wrapper around a `StaticMap<L, T>` or `StaticCopyMap<L, T>` whose `Deserialize`
+    /// implementation rejects duplicate keys.
+    ///
+    /// The wrapper is serialized like the wrapped map.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use serde_1::{Serialize, Deserialize};
+    /// # use linearize::{StaticCopyMap, StaticMap};
+    /// # use linearize::serde_1::strict::Strict;
+    /// #[derive(Serialize, Deserialize)]
+    /// # #[serde(crate = "serde_1")]
+    /// struct X {
+    ///     map: Strict<StaticMap<bool, u8>>,
+    ///     copy: Strict<StaticCopyMap<bool, u8>>,
+    /// }
+    /// ```
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Strict<M>(pub M);
+
+    impl<M> Deref for Strict<M> {
+        type Target = M;
+
+        #[inline]
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl<M> DerefMut for Strict<M> {
+        #[inline]
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+
+    impl<M> Serialize for Strict<M>
+    where
+        M: Serialize,
+    {
+        #[inline]
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.0.serialize(serializer)
+        }
+    }
+
+    impl<'de, L, T> Deserialize<'de> for Strict<StaticMap<L, T>>
+    where
+        L: Deserialize<'de> + Linearize + Debug,
+        T: Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize(deserializer).map(Strict)
+        }
+    }
+
+    impl<'de, L, T> Deserialize<'de> for Strict<StaticCopyMap<L, T>>
+    where
+        L: Deserialize<'de> + Linearize + Debug,
+        T: Deserialize<'de> + Copy,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize(deserializer).map(Strict)
+        }
+    }
+
+    struct StrictVisitor<L, T>(PhantomData<fn() -> StaticMap<L, T>>)
     where
         L: Linearize;
 
-    impl<'de, L, T> Visitor<'de> for V<L, T>
+    impl<'de, L, T> Visitor<'de> for StrictVisitor<L, T>
     where
-        L: Deserialize<'de> + Linearize,
-        T: Deserialize<'de> + Default,
+        L: Linearize + Debug + Deserialize<'de>,
+        T: Deserialize<'de>,
     {
         type Value = StaticMap<L, T>;
 
@@ -266,10 +560,763 @@ pub mod use_default {
             A: MapAccess<'de>,
         {
             let mut res = StaticMap::<L, Option<T>>::default();
-            while let Some((k, v)) = map.next_entry::<L, T>()? {
-                res[k] = Some(v);
+            while let Some(k) = map.next_key::<L>()? {
+                let k = k.linearized();
+                if res[k].is_some() {
+                    return Err(Error::custom(DuplicateKey(k.delinearize())));
+                }
+                res[k] = Some(map.next_value()?);
+            }
+            if res.values().any(|v| v.is_none()) {
+                return Err(Error::custom(MissingKeys(&res)));
+            }
+            Ok(res.map_values(|v| unsafe {
+                // SAFETY: We just checked that all values are Some.
+                v.unwrap_unchecked()
+            }))
+        }
+    }
+
+    struct DuplicateKey<L>(L);
+
+    impl<L: Debug> Display for DuplicateKey<L> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            write!(f, "Duplicate key {:?} in static map", self.0)
+        }
+    }
+}
+
+mod seq {
+    use {
+        crate::{Linearize, StaticMap},
+        core::{fmt::Formatter, marker::PhantomData},
+        serde_1::{
+            de::{Error, IgnoredAny, SeqAccess, Visitor},
+            Deserialize,
+        },
+    };
+
+    pub(super) struct SeqVisitor<L, T>(pub(super) PhantomData<fn() -> StaticMap<L, T>>)
+    where
+        L: Linearize;
+
+    impl<'de, L, T> Visitor<'de> for SeqVisitor<L, T>
+    where
+        L: Linearize,
+        T: Deserialize<'de>,
+    {
+        type Value = StaticMap<L, T>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+            write!(formatter, "a sequence of length {}", L::LENGTH)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut res = StaticMap::<L, Option<T>>::default();
+            for (i, v) in res.values_mut().enumerate() {
+                match seq.next_element()? {
+                    Some(e) => *v = Some(e),
+                    None => return Err(Error::invalid_length(i, &self)),
+                }
+            }
+            if seq.next_element::<IgnoredAny>()?.is_some() {
+                let len = seq.size_hint().unwrap_or(0) + L::LENGTH + 1;
+                return Err(Error::invalid_length(len, &self));
             }
-            Ok(res.map_values(|v| v.unwrap_or_default()))
+            Ok(res.map_values(|v| unsafe {
+                // SAFETY: The loop above initialized all values.
+                v.unwrap_unchecked()
+            }))
+        }
+    }
+}
+
+/// A de/serialize implementation for `StaticMap<L, T>` and `StaticCopyMap<L, T>` that
+/// uses the wire format of a tuple `(T, T, ...)` of length `L::LENGTH`.
+///
+/// The values are serialized in the order of their keys' linearization. The keys
+/// themselves are not serialized and therefore do not have to implement `Serialize`.
+/// Binary formats such as bincode and postcard do not store the length of tuples.
+///
+/// The deserializer fails if the sequence does not contain exactly `L::LENGTH` values.
+/// Use [`as_seq`] to also store the length in binary formats.
+///
+/// # Example
+///
+/// ```rust
+/// # use serde_1::{Serialize, Deserialize};
+/// # use linearize::StaticMap;
+/// #[derive(Serialize, Deserialize)]
+/// # #[serde(crate = "serde_1")]
+/// struct X {
+///     #[serde(with = "linearize::serde_1::as_tuple")]
+///     map: StaticMap<bool, u8>,
+/// }
+/// ```
+pub mod as_tuple {
+    use {
+        super::seq::SeqVisitor,
+        crate::{Linearize, StaticMap},
+        core::{borrow::Borrow, marker::PhantomData},
+        serde_1::{ser::SerializeTuple, Deserialize, Deserializer, Serialize, Serializer},
+    };
+
+    pub fn serialize<L, T, S, M>(static_map: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        L: Linearize,
+        T: Serialize,
+        S: Serializer,
+        M: Borrow<StaticMap<L, T>>,
+    {
+        let mut tuple = serializer.serialize_tuple(L::LENGTH)?;
+        for v in static_map.borrow().values() {
+            tuple.serialize_element(v)?;
+        }
+        tuple.end()
+    }
+
+    pub fn deserialize<'de, L, T, D, O>(deserializer: D) -> Result<O, D::Error>
+    where
+        L: Linearize,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+        O: From<StaticMap<L, T>>,
+    {
+        deserializer
+            .deserialize_tuple(L::LENGTH, SeqVisitor(PhantomData))
+            .map(|v| v.into())
+    }
+}
+
+/// A de/serialize implementation for `StaticMap<L, T>` and `StaticCopyMap<L, T>` that
+/// uses the wire format of a sequence `[T]` of length `L::LENGTH`.
+///
+/// This is the same as [`as_tuple`] except that binary formats such as bincode and
+/// postcard prefix the values with `L::LENGTH`. This allows detecting data that was
+/// serialized with a different number of keys.
+///
+/// # Example
+///
+/// ```rust
+/// # use serde_1::{Serialize, Deserialize};
+/// # use linearize::StaticMap;
+/// #[derive(Serialize, Deserialize)]
+/// # #[serde(crate = "serde_1")]
+/// struct X {
+///     #[serde(with = "linearize::serde_1::as_seq")]
+///     map: StaticMap<bool, u8>,
+/// }
+/// ```
+pub mod as_seq {
+    use {
+        super::seq::SeqVisitor,
+        crate::{Linearize, StaticMap},
+        core::{borrow::Borrow, marker::PhantomData},
+        serde_1::{ser::SerializeSeq, Deserialize, Deserializer, Serialize, Serializer},
+    };
+
+    pub fn serialize<L, T, S, M>(static_map: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        L: Linearize,
+        T: Serialize,
+        S: Serializer,
+        M: Borrow<StaticMap<L, T>>,
+    {
+        let mut seq = serializer.serialize_seq(Some(L::LENGTH))?;
+        for v in static_map.borrow().values() {
+            seq.serialize_element(v)?;
+        }
+        seq.end()
+    }
+
+    pub fn deserialize<'de, L, T, D, O>(deserializer: D) -> Result<O, D::Error>
+    where
+        L: Linearize,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+        O: From<StaticMap<L, T>>,
+    {
+        deserializer
+            .deserialize_seq(SeqVisitor(PhantomData))
+            .map(|v| v.into())
+    }
+}
+
+/// A de/serialize implementation for `StaticMap<L, T>` that uses the linear index of
+/// each key as the key.
+///
+/// The wire format is that of `HashMap<usize, T>`. The keys do not have to implement
+/// `Serialize` or `Deserialize`. This is more compact than the default format for keys
+/// whose own representation is verbose.
+///
+/// The deserializer fails if an index is not less than `L::LENGTH` or if any key is
+/// missing.
+///
+/// # Example
+///
+/// ```rust
+/// # use serde_1::{Serialize, Deserialize};
+/// # use linearize::StaticMap;
+/// #[derive(Serialize, Deserialize)]
+/// # #[serde(crate = "serde_1")]
+/// struct X {
+///     #[serde(with = "linearize::serde_1::index_keys")]
+///     map: StaticMap<bool, u8>,
+/// }
+/// ```
+pub mod index_keys {
+    use {
+        crate::{Linearize, Linearized, StaticMap},
+        core::{
+            borrow::Borrow,
+            fmt::{Display, Formatter},
+            marker::PhantomData,
+        },
+        serde_1::{
+            de::{Error, MapAccess, Visitor},
+            ser::SerializeMap,
+            Deserialize, Deserializer, Serialize, Serializer,
+        },
+    };
+
+    pub fn serialize<L, T, S, M>(static_map: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        L: Linearize,
+        T: Serialize,
+        S: Serializer,
+        M: Borrow<StaticMap<L, T>>,
+    {
+        let mut map = serializer.serialize_map(Some(L::LENGTH))?;
+        for (i, v) in static_map.borrow().values().enumerate() {
+            map.serialize_entry(&i, v)?;
+        }
+        map.end()
+    }
+
+    pub fn deserialize<'de, L, T, D, O>(deserializer: D) -> Result<O, D::Error>
+    where
+        L: Linearize,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+        O: From<StaticMap<L, T>>,
+    {
+        deserializer
+            .deserialize_map(V(PhantomData))
+            .map(|v| v.into())
+    }
+
+    struct V<L, T>(PhantomData<fn() -> StaticMap<L, T>>)
+    where
+        L: Linearize;
+
+    impl<'de, L, T> Visitor<'de> for V<L, T>
+    where
+        L: Linearize,
+        T: Deserialize<'de>,
+    {
+        type Value = StaticMap<L, T>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+            write!(formatter, "a map")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut res = StaticMap::<L, Option<T>>::default();
+            while let Some((k, v)) = map.next_entry::<Linearized<L>, T>()? {
+                res[k] = Some(v);
+            }
+            if let Some(idx) = res.values().position(|v| v.is_none()) {
+                return Err(Error::custom(MissingIndex(idx)));
+            }
+            Ok(res.map_values(|v| unsafe {
+                // SAFETY: We just checked that all values are Some.
+                v.unwrap_unchecked()
+            }))
+        }
+    }
+
+    struct MissingIndex(usize);
+
+    impl Display for MissingIndex {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            write!(f, "Missing key with index {} in static map", self.0)
+        }
+    }
+}
+
+/// A de/serialize implementation for `StaticMap<L, T>` that uses the `Display`
+/// representation of each key as the key.
+///
+/// The wire format is that of `HashMap<String, T>`. This allows using key types that
+/// cannot be used as keys in formats such as JSON, for example structs, as long as they
+/// implement `Display`.
+///
+/// The deserializer parses each key with the `FromStr` implementation of `L`, which
+/// should be the inverse of its `Display` implementation. If a key cannot be parsed, the
+/// error lists the valid keys. The deserializer fails if any key is missing. All missing
+/// keys are reported in a single error.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::{fmt::{Display, Formatter}, str::FromStr};
+/// # use serde_1::{Serialize, Deserialize};
+/// # use linearize::{Linearize, StaticMap};
+/// #[derive(Linearize)]
+/// struct Pos {
+///     x: bool,
+///     y: bool,
+/// }
+///
+/// impl Display for Pos {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{},{}", self.x as u8, self.y as u8)
+///     }
+/// }
+///
+/// impl FromStr for Pos {
+///     type Err = ();
+///
+///     fn from_str(s: &str) -> Result<Self, ()> {
+///         let bit = |s| match s {
+///             "0" => Ok(false),
+///             "1" => Ok(true),
+///             _ => Err(()),
+///         };
+///         let (x, y) = s.split_once(',').ok_or(())?;
+///         Ok(Self { x: bit(x)?, y: bit(y)? })
+///     }
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// # #[serde(crate = "serde_1")]
+/// struct X {
+///     #[serde(with = "linearize::serde_1::display_keys")]
+///     map: StaticMap<Pos, u8>,
+/// }
+/// ```
+pub mod display_keys {
+    use {
+        super::default::fmt_missing_keys,
+        crate::{Linearize, LinearizeExt, Linearized, StaticMap},
+        core::{
+            borrow::Borrow,
+            fmt::{Display, Formatter},
+            marker::PhantomData,
+            str::FromStr,
+        },
+        serde_1::{
+            de::{DeserializeSeed, Error, MapAccess, Visitor},
+            ser::SerializeMap,
+            Deserialize, Deserializer, Serialize, Serializer,
+        },
+    };
+
+    /// The maximum number of valid keys listed in errors.
+    const MAX_LISTED: usize = 32;
+
+    pub fn serialize<L, T, S, M>(static_map: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        L: Linearize + Display,
+        T: Serialize,
+        S: Serializer,
+        M: Borrow<StaticMap<L, T>>,
+    {
+        let mut map = serializer.serialize_map(Some(L::LENGTH))?;
+        for (k, v) in static_map.borrow() {
+            map.serialize_entry(&DisplayKey(&k), v)?;
+        }
+        map.end()
+    }
+
+    pub fn deserialize<'de, L, T, D, O>(deserializer: D) -> Result<O, D::Error>
+    where
+        L: Linearize + Display + FromStr,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+        O: From<StaticMap<L, T>>,
+    {
+        deserializer
+            .deserialize_map(V(PhantomData))
+            .map(|v| v.into())
+    }
+
+    struct DisplayKey<'a, L>(&'a L);
+
+    impl<L> Serialize for DisplayKey<'_, L>
+    where
+        L: Display,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_str(self.0)
+        }
+    }
+
+    struct V<L, T>(PhantomData<fn() -> StaticMap<L, T>>)
+    where
+        L: Linearize;
+
+    impl<'de, L, T> Visitor<'de> for V<L, T>
+    where
+        L: Linearize + Display + FromStr,
+        T: Deserialize<'de>,
+    {
+        type Value = StaticMap<L, T>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+            write!(formatter, "a map")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut res = StaticMap::<L, Option<T>>::default();
+            while let Some(k) = map.next_key_seed(KeySeed::<L>(PhantomData))? {
+                res[k] = Some(map.next_value()?);
+            }
+            if res.values().any(|v| v.is_none()) {
+                return Err(Error::custom(MissingKeys(&res)));
+            }
+            Ok(res.map_values(|v| unsafe {
+                // SAFETY: We just checked that all values are Some.
+                v.unwrap_unchecked()
+            }))
+        }
+    }
+
+    struct KeySeed<L>(PhantomData<fn() -> L>);
+
+    impl<'de, L> DeserializeSeed<'de> for KeySeed<L>
+    where
+        L: Linearize + Display + FromStr,
+    {
+        type Value = Linearized<L>;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    impl<L> Visitor<'_> for KeySeed<L>
+    where
+        L: Linearize + Display + FromStr,
+    {
+        type Value = Linearized<L>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+            write!(formatter, "a string")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            match v.parse::<L>() {
+                Ok(k) => Ok(k.linearized()),
+                Err(_) => Err(E::custom(UnknownKey::<L> {
+                    key: v,
+                    _phantom: PhantomData,
+                })),
+            }
+        }
+    }
+
+    struct MissingKeys<'a, L, T>(&'a StaticMap<L, Option<T>>)
+    where
+        L: Linearize;
+
+    impl<L, T> Display for MissingKeys<'_, L, T>
+    where
+        L: Linearize + Display,
+    {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            fmt_missing_keys(f, self.0, |f, k| write!(f, "\"{}\"", k))
+        }
+    }
+
+    struct UnknownKey<'a, L> {
+        key: &'a str,
+        _phantom: PhantomData<fn() -> L>,
+    }
+
+    impl<L> Display for UnknownKey<'_, L>
+    where
+        L: Linearize + Display,
+    {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            write!(f, "Unknown key \"{}\", expected one of ", self.key)?;
+            for i in 0..L::LENGTH.min(MAX_LISTED) {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                let k = unsafe {
+                    // SAFETY: i < L::LENGTH.
+                    L::from_linear_unchecked(i)
+                };
+                write!(f, "\"{}\"", k)?;
+            }
+            if L::LENGTH > MAX_LISTED {
+                f.write_str(", ...")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Deserialize implementations for `StaticMap<L, T>` that migrate data written with an
+/// older version of `L`.
+///
+/// The keys must be serialized as strings, for example by using a derived `Deserialize`
+/// implementation for a fieldless enum with a self-describing format such as JSON. The
+/// migration is described by an implementation of [`Migration`](migrate::Migration):
+///
+/// - Each key is first deserialized with `L`'s own `Deserialize` implementation. If that
+///   fails, [`Migration::alias`](migrate::Migration::alias) is used to map old names to new keys.
+/// - Keys that cannot be mapped are handled according to [`Migration::DROPPED_KEYS`](migrate::Migration::DROPPED_KEYS).
+/// - Missing keys are filled with [`Migration::default_value`](migrate::Migration::default_value).
+///
+/// The [`deserialize`](migrate::deserialize) function fails if a missing key has no
+/// default value. [`deserialize_use_default`](migrate::deserialize_use_default) and
+/// [`deserialize_skip_none`](migrate::deserialize_skip_none) fall back to the behavior of
+/// the [`use_default`] and [`skip_none`] modules respectively.
+///
+/// # Example
+///
+/// ```rust
+/// # use serde_1::Deserialize;
+/// # use linearize::{Linearize, StaticMap};
+/// # use linearize::serde_1::migrate::{DroppedKeys, Migration};
+/// #[derive(Linearize, Deserialize, Debug)]
+/// # #[serde(crate = "serde_1")]
+/// enum Color {
+///     // Renamed from `Crimson`.
+///     Red,
+///     Green,
+///     // Added in version 2.
+///     Blue,
+/// }
+///
+/// struct ColorMigration;
+///
+/// impl Migration<Color, u8> for ColorMigration {
+///     // `Yellow` was removed in version 2.
+///     const DROPPED_KEYS: DroppedKeys = DroppedKeys::Ignore;
+///
+///     fn alias(name: &str) -> Option<Color> {
+///         match name {
+///             "Crimson" => Some(Color::Red),
+///             _ => None,
+///         }
+///     }
+///
+///     fn default_value(key: &Color) -> Option<u8> {
+///         match key {
+///             Color::Blue => Some(255),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// #[derive(Deserialize)]
+/// # #[serde(crate = "serde_1")]
+/// struct X {
+///     #[serde(deserialize_with = "linearize::serde_1::migrate::deserialize::<ColorMigration, _, _, _, _>")]
+///     map: StaticMap<Color, u8>,
+/// }
+/// ```
+pub mod migrate {
+    use {
+        super::{default::MissingKeys, skip_none::V},
+        crate::{Linearize, StaticMap},
+        core::{
+            fmt::{Debug, Display, Formatter},
+            marker::PhantomData,
+        },
+        serde_1::{
+            de::{self, DeserializeSeed, Error, IntoDeserializer, Visitor},
+            Deserialize, Deserializer,
+        },
+    };
+
+    /// A description of how to migrate serialized maps.
+    ///
+    /// See the [module documentation](self).
+    pub trait Migration<L, T> {
+        /// How to handle keys that are neither keys of `L` nor aliases.
+        const DROPPED_KEYS: DroppedKeys = DroppedKeys::Ignore;
+
+        /// Returns the key that replaces the old key `name`.
+        fn alias(name: &str) -> Option<L> {
+            let _ = name;
+            None
+        }
+
+        /// Returns the value to use if `key` is missing.
+        fn default_value(key: &L) -> Option<T> {
+            let _ = key;
+            None
+        }
+    }
+
+    /// How to handle keys that are no longer part of a key type.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    pub enum DroppedKeys {
+        /// Ignore the key and its value.
+        Ignore,
+        /// Fail deserialization.
+        Error,
+    }
+
+    /// Deserializes a map and fails if a missing key has no default value.
+    pub fn deserialize<'de, M, L, T, D, O>(deserializer: D) -> Result<O, D::Error>
+    where
+        M: Migration<L, T>,
+        L: Deserialize<'de> + Linearize + Debug,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+        O: From<StaticMap<L, T>>,
+    {
+        let res = deserialize_skip_none::<M, L, T, D, StaticMap<L, Option<T>>>(deserializer)?;
+        if res.values().any(|v| v.is_none()) {
+            return Err(D::Error::custom(MissingKeys(&res)));
+        }
+        Ok(res
+            .map_values(|v| unsafe {
+                // SAFETY: We just checked that all values are Some.
+                v.unwrap_unchecked()
+            })
+            .into())
+    }
+
+    /// Deserializes a map and uses `T::default()` for missing keys without a default
+    /// value.
+    pub fn deserialize_use_default<'de, M, L, T, D, O>(deserializer: D) -> Result<O, D::Error>
+    where
+        M: Migration<L, T>,
+        L: Deserialize<'de> + Linearize,
+        T: Deserialize<'de> + Default,
+        D: Deserializer<'de>,
+        O: From<StaticMap<L, T>>,
+    {
+        deserialize_skip_none::<M, L, T, D, StaticMap<L, Option<T>>>(deserializer)
+            .map(|v| v.map_values(|v| v.unwrap_or_default()).into())
+    }
+
+    /// Deserializes a map and uses `None` for missing keys without a default value.
+    pub fn deserialize_skip_none<'de, M, L, T, D, O>(deserializer: D) -> Result<O, D::Error>
+    where
+        M: Migration<L, T>,
+        L: Deserialize<'de> + Linearize,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+        O: From<StaticMap<L, Option<T>>>,
+    {
+        let mut res =
+            deserializer.deserialize_map(V(KeySeed::<M, L, T>(PhantomData), PhantomData))?;
+        for (k, v) in res.iter_mut() {
+            if v.is_none() {
+                *v = M::default_value(&k);
+            }
+        }
+        Ok(res.into())
+    }
+
+    struct KeySeed<M, L, T>(PhantomData<fn(M, T) -> L>);
+
+    impl<M, L, T> Clone for KeySeed<M, L, T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<M, L, T> Copy for KeySeed<M, L, T> {}
+
+    impl<'de, M, L, T> DeserializeSeed<'de> for KeySeed<M, L, T>
+    where
+        M: Migration<L, T>,
+        L: Deserialize<'de> + Linearize,
+    {
+        type Value = Option<L>;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    impl<'de, M, L, T> Visitor<'de> for KeySeed<M, L, T>
+    where
+        M: Migration<L, T>,
+        L: Deserialize<'de> + Linearize,
+    {
+        type Value = Option<L>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+            write!(formatter, "a string")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            let de: de::value::StrDeserializer<'_, de::value::Error> = v.into_deserializer();
+            if let Ok(k) = L::deserialize(de) {
+                return Ok(Some(k));
+            }
+            self.resolve(v)
+        }
+
+        fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            let de = de::value::BorrowedStrDeserializer::<de::value::Error>::new(v);
+            if let Ok(k) = L::deserialize(de) {
+                return Ok(Some(k));
+            }
+            self.resolve(v)
+        }
+    }
+
+    impl<M, L, T> KeySeed<M, L, T>
+    where
+        M: Migration<L, T>,
+        L: Linearize,
+    {
+        fn resolve<E>(self, v: &str) -> Result<Option<L>, E>
+        where
+            E: Error,
+        {
+            if let Some(k) = M::alias(v) {
+                return Ok(Some(k));
+            }
+            match M::DROPPED_KEYS {
+                DroppedKeys::Ignore => Ok(None),
+                DroppedKeys::Error => Err(E::custom(DroppedKey(v))),
+            }
+        }
+    }
+
+    struct DroppedKey<'a>(&'a str);
+
+    impl Display for DroppedKey<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            write!(f, "Unknown key \"{}\" in static map", self.0)
         }
     }
 }
@@ -0,0 +1,644 @@
+use {
+    crate::{Linearize, LinearizeExt, StaticMap},
+    alloc::{boxed::Box, collections::BinaryHeap, vec::Vec},
+    core::{
+        cmp::Reverse,
+        fmt::{Debug, Display, Formatter},
+        hash::{Hash, Hasher},
+        marker::PhantomData,
+    },
+};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A dense directed graph whose nodes are the values of `L`.
+///
+/// The adjacency matrix is stored as `L::LENGTH * L::LENGTH` bits. Self-loops are
+/// allowed.
+///
+/// This type is only available with the `alloc` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use linearize::{Linearize, StaticGraph};
+/// #[derive(Linearize, Copy, Clone, Debug, PartialEq)]
+/// enum Stage {
+///     Fetch,
+///     Decode,
+///     Execute,
+/// }
+///
+/// let graph: StaticGraph<_> = [
+///     (Stage::Decode, Stage::Execute),
+///     (Stage::Fetch, Stage::Decode),
+/// ]
+/// .into_iter()
+/// .collect();
+/// assert!(graph.is_reachable(&Stage::Fetch, &Stage::Execute));
+/// let ranks = graph.topological_sort().unwrap();
+/// assert_eq!(ranks[Stage::Fetch], 0);
+/// assert_eq!(ranks[Stage::Execute], 2);
+/// ```
+pub struct StaticGraph<L>
+where
+    L: Linearize,
+{
+    bits: Box<[u64]>,
+    _phantom: PhantomData<fn() -> L>,
+}
+
+/// The error returned by [`StaticGraph::topological_sort`] if the graph contains a
+/// cycle.
+pub struct Cyclic<L> {
+    cycle: Vec<L>,
+}
+
+/// An iterator over the successors of a node in a [`StaticGraph`].
+///
+/// See [`StaticGraph::successors`].
+pub struct Successors<'a, L>
+where
+    L: Linearize,
+{
+    row: &'a [u64],
+    word: usize,
+    bits: u64,
+    _phantom: PhantomData<fn() -> L>,
+}
+
+/// An iterator over the predecessors of a node in a [`StaticGraph`].
+///
+/// See [`StaticGraph::predecessors`].
+pub struct Predecessors<'a, L>
+where
+    L: Linearize,
+{
+    graph: &'a StaticGraph<L>,
+    to: usize,
+    next: usize,
+}
+
+impl<L> StaticGraph<L>
+where
+    L: Linearize,
+{
+    const WORDS_PER_ROW: usize = L::LENGTH.div_ceil(BITS);
+
+    /// Creates a graph without edges.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            bits: alloc::vec![0; L::LENGTH * Self::WORDS_PER_ROW].into_boxed_slice(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Creates the complete graph, containing an edge between every pair of nodes,
+    /// including self-loops.
+    pub fn complete() -> Self {
+        let mut res = Self::new();
+        for from in 0..L::LENGTH {
+            for to in 0..L::LENGTH {
+                res.set(from, to);
+            }
+        }
+        res
+    }
+
+    #[inline]
+    fn row(&self, from: usize) -> &[u64] {
+        let start = from * Self::WORDS_PER_ROW;
+        &self.bits[start..start + Self::WORDS_PER_ROW]
+    }
+
+    #[inline]
+    fn row_mut(&mut self, from: usize) -> &mut [u64] {
+        let start = from * Self::WORDS_PER_ROW;
+        &mut self.bits[start..start + Self::WORDS_PER_ROW]
+    }
+
+    #[inline]
+    fn has(&self, from: usize, to: usize) -> bool {
+        self.row(from)[to / BITS] & (1 << (to % BITS)) != 0
+    }
+
+    #[inline]
+    fn set(&mut self, from: usize, to: usize) -> bool {
+        let word = &mut self.row_mut(from)[to / BITS];
+        let mask = 1 << (to % BITS);
+        let added = *word & mask == 0;
+        *word |= mask;
+        added
+    }
+
+    /// Adds an edge from `from` to `to`.
+    ///
+    /// Returns whether the edge was newly added.
+    #[inline]
+    pub fn add_edge(&mut self, from: &L, to: &L) -> bool {
+        self.set(from.linearize(), to.linearize())
+    }
+
+    /// Removes the edge from `from` to `to`.
+    ///
+    /// Returns whether the edge was present.
+    #[inline]
+    pub fn remove_edge(&mut self, from: &L, to: &L) -> bool {
+        let to = to.linearize();
+        let word = &mut self.row_mut(from.linearize())[to / BITS];
+        let mask = 1 << (to % BITS);
+        let removed = *word & mask != 0;
+        *word &= !mask;
+        removed
+    }
+
+    /// Returns whether the graph contains an edge from `from` to `to`.
+    #[inline]
+    pub fn contains_edge(&self, from: &L, to: &L) -> bool {
+        self.has(from.linearize(), to.linearize())
+    }
+
+    /// Returns the number of edges in the graph.
+    #[inline]
+    pub fn edge_count(&self) -> usize {
+        self.bits.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Removes all edges from the graph.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+    }
+
+    /// Returns an iterator over the nodes that have an edge from `from`.
+    ///
+    /// Nodes are ordered by their linearization.
+    #[inline]
+    pub fn successors(&self, from: &L) -> Successors<'_, L> {
+        let row = self.row(from.linearize());
+        Successors {
+            row,
+            word: 0,
+            bits: row.first().copied().unwrap_or(0),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the nodes that have an edge to `to`.
+    ///
+    /// Nodes are ordered by their linearization.
+    #[inline]
+    pub fn predecessors(&self, to: &L) -> Predecessors<'_, L> {
+        Predecessors {
+            graph: self,
+            to: to.linearize(),
+            next: 0,
+        }
+    }
+
+    /// Returns the graph with all edges reversed.
+    pub fn reverse(&self) -> Self {
+        let mut res = Self::new();
+        for from in 0..L::LENGTH {
+            for to in 0..L::LENGTH {
+                if self.has(from, to) {
+                    res.set(to, from);
+                }
+            }
+        }
+        res
+    }
+
+    /// Returns the transitive closure of this graph.
+    ///
+    /// The result contains an edge from `a` to `b` if and only if there is a non-empty
+    /// path from `a` to `b` in this graph.
+    ///
+    /// This function uses Warshall's algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::StaticGraph;
+    /// let graph: StaticGraph<u8> = [(1, 2), (2, 3)].into_iter().collect();
+    /// let closure = graph.transitive_closure();
+    /// assert!(closure.contains_edge(&1, &3));
+    /// assert!(!closure.contains_edge(&1, &1));
+    /// ```
+    pub fn transitive_closure(&self) -> Self {
+        let mut res = self.clone();
+        let words = Self::WORDS_PER_ROW;
+        for k in 0..L::LENGTH {
+            for i in 0..L::LENGTH {
+                if i != k && res.has(i, k) {
+                    let (row_i, row_k) = if i < k {
+                        let (a, b) = res.bits.split_at_mut(k * words);
+                        (&mut a[i * words..][..words], &b[..words])
+                    } else {
+                        let (a, b) = res.bits.split_at_mut(i * words);
+                        (&mut b[..words], &a[k * words..][..words])
+                    };
+                    for (a, b) in row_i.iter_mut().zip(row_k) {
+                        *a |= *b;
+                    }
+                }
+            }
+        }
+        res
+    }
+
+    /// Returns the set of nodes that are reachable from `from`.
+    ///
+    /// Every node is reachable from itself.
+    pub fn reachable_from(&self, from: &L) -> StaticMap<L, bool> {
+        let mut visited = StaticMap::<L, bool>::default();
+        let from = from.linearized();
+        visited[from] = true;
+        let mut stack = alloc::vec![from];
+        while let Some(node) = stack.pop() {
+            for next in self.successors(&node.delinearize()) {
+                let next = next.linearized();
+                if !visited[next] {
+                    visited[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        visited
+    }
+
+    /// Returns whether there is a path from `from` to `to`.
+    ///
+    /// Every node is reachable from itself.
+    #[inline]
+    pub fn is_reachable(&self, from: &L, to: &L) -> bool {
+        self.reachable_from(from)[to]
+    }
+
+    /// Sorts the nodes topologically.
+    ///
+    /// On success, returns the position of each node in the order. If there are multiple
+    /// valid orders, the lexicographically smallest one is returned, that is, whenever
+    /// several nodes could come next, the one with the smallest linearization is placed
+    /// first.
+    ///
+    /// If the graph contains a cycle, the cycle is returned in the error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::StaticGraph;
+    /// let graph: StaticGraph<bool> = [(true, false), (false, true)].into_iter().collect();
+    /// let err = graph.topological_sort().unwrap_err();
+    /// assert_eq!(err.cycle(), [false, true]);
+    /// ```
+    pub fn topological_sort(&self) -> Result<StaticMap<L, usize>, Cyclic<L>> {
+        let mut in_degree = StaticMap::<L, usize>::default();
+        for from in L::variants() {
+            for to in self.successors(&from) {
+                in_degree[to] += 1;
+            }
+        }
+        let mut ready: BinaryHeap<_> = L::variants()
+            .filter(|l| in_degree[l] == 0)
+            .map(|l| Reverse(l.linearized()))
+            .collect();
+        let mut rank = StaticMap::<L, Option<usize>>::default();
+        let mut next_rank = 0;
+        while let Some(Reverse(node)) = ready.pop() {
+            rank[node] = Some(next_rank);
+            next_rank += 1;
+            for next in self.successors(&node.delinearize()) {
+                let next = next.linearized();
+                in_degree[next] -= 1;
+                if in_degree[next] == 0 {
+                    ready.push(Reverse(next));
+                }
+            }
+        }
+        if next_rank == L::LENGTH {
+            return Ok(rank.map_values(|r| r.unwrap()));
+        }
+        // Every node that has not been ranked has a predecessor that has not been
+        // ranked. Walking backwards along such predecessors must eventually revisit a
+        // node.
+        let mut on_path = StaticMap::<L, Option<usize>>::default();
+        let mut path = alloc::vec![];
+        let mut node = rank.iter().find(|(_, r)| r.is_none()).unwrap().0;
+        while on_path[&node].is_none() {
+            on_path[&node] = Some(path.len());
+            let prev = self
+                .predecessors(&node)
+                .find(|p| rank[p].is_none())
+                .unwrap();
+            path.push(node);
+            node = prev;
+        }
+        let mut cycle = path.split_off(on_path[&node].unwrap());
+        cycle.reverse();
+        let start = (0..cycle.len())
+            .min_by_key(|&i| cycle[i].linearize())
+            .unwrap();
+        cycle.rotate_left(start);
+        Err(Cyclic { cycle })
+    }
+
+    /// Computes the strongly connected components of this graph.
+    ///
+    /// Returns the index of the component of each node. The components are numbered
+    /// from `0` in a topological order of the condensation of the graph, that is, if
+    /// there is an edge from a node in component `a` to a node in component `b != a`,
+    /// then `a < b`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::StaticGraph;
+    /// let graph: StaticGraph<u8> = [(1, 2), (2, 1), (2, 3)].into_iter().collect();
+    /// let scc = graph.strongly_connected_components();
+    /// assert_eq!(scc[1], scc[2]);
+    /// assert!(scc[2] < scc[3]);
+    /// ```
+    pub fn strongly_connected_components(&self) -> StaticMap<L, usize> {
+        let closure = self.transitive_closure();
+        let reaches = |a: usize, b: usize| a == b || closure.has(a, b);
+        // The representative of each component is its node with the smallest
+        // linearization.
+        let component: Vec<_> = (0..L::LENGTH)
+            .map(|i| (0..=i).find(|&j| reaches(i, j) && reaches(j, i)).unwrap())
+            .collect();
+        let mut representatives: Vec<_> = (0..L::LENGTH).filter(|&i| component[i] == i).collect();
+        // If component a reaches component b != a, then the set of nodes reachable from
+        // a is a strict superset of the set of nodes reachable from b.
+        let reachable = |i: usize| {
+            let successors: u32 = closure.row(i).iter().map(|w| w.count_ones()).sum();
+            successors + !closure.has(i, i) as u32
+        };
+        representatives.sort_by_key(|&r| (Reverse(reachable(r)), r));
+        let mut index = alloc::vec![0; L::LENGTH];
+        for (n, &r) in representatives.iter().enumerate() {
+            index[r] = n;
+        }
+        StaticMap::from_fn(|l: L| index[component[l.linearize()]])
+    }
+}
+
+impl<L> Cyclic<L> {
+    /// Returns the nodes of the cycle.
+    ///
+    /// For each node in the slice there is an edge to the next node and there is an
+    /// edge from the last node to the first node.
+    #[inline]
+    pub fn cycle(&self) -> &[L] {
+        &self.cycle
+    }
+
+    /// Returns the nodes of the cycle.
+    #[inline]
+    pub fn into_cycle(self) -> Vec<L> {
+        self.cycle
+    }
+}
+
+impl<L> Clone for Cyclic<L>
+where
+    L: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            cycle: self.cycle.clone(),
+        }
+    }
+}
+
+impl<L> PartialEq for Cyclic<L>
+where
+    L: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cycle == other.cycle
+    }
+}
+
+impl<L> Eq for Cyclic<L> where L: Eq {}
+
+impl<L> Debug for Cyclic<L>
+where
+    L: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Cyclic")
+            .field("cycle", &self.cycle)
+            .finish()
+    }
+}
+
+impl<L> Display for Cyclic<L>
+where
+    L: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Graph contains a cycle: {:?}", self.cycle)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<L> std::error::Error for Cyclic<L> where L: Debug {}
+
+impl<L> Default for StaticGraph<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L> Clone for StaticGraph<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            bits: self.bits.clone(),
+            _phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.bits.copy_from_slice(&source.bits);
+    }
+}
+
+impl<L> PartialEq for StaticGraph<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
+}
+
+impl<L> Eq for StaticGraph<L> where L: Linearize {}
+
+impl<L> Hash for StaticGraph<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits.hash(state);
+    }
+}
+
+impl<L> Debug for StaticGraph<L>
+where
+    L: Linearize + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        struct Row<'a, L>(&'a StaticGraph<L>, L)
+        where
+            L: Linearize;
+        impl<L> Debug for Row<'_, L>
+        where
+            L: Linearize + Debug,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                f.debug_set().entries(self.0.successors(&self.1)).finish()
+            }
+        }
+        f.debug_map()
+            .entries(L::variants().map(|l| (l.linearized().delinearize(), Row(self, l))))
+            .finish()
+    }
+}
+
+impl<L> FromIterator<(L, L)> for StaticGraph<L>
+where
+    L: Linearize,
+{
+    fn from_iter<I: IntoIterator<Item = (L, L)>>(iter: I) -> Self {
+        let mut res = Self::new();
+        res.extend(iter);
+        res
+    }
+}
+
+impl<L> Extend<(L, L)> for StaticGraph<L>
+where
+    L: Linearize,
+{
+    fn extend<I: IntoIterator<Item = (L, L)>>(&mut self, iter: I) {
+        for (from, to) in iter {
+            self.add_edge(&from, &to);
+        }
+    }
+}
+
+impl<'a, L> Extend<(&'a L, &'a L)> for StaticGraph<L>
+where
+    L: Linearize,
+{
+    fn extend<I: IntoIterator<Item = (&'a L, &'a L)>>(&mut self, iter: I) {
+        for (from, to) in iter {
+            self.add_edge(from, to);
+        }
+    }
+}
+
+impl<L> Iterator for Successors<'_, L>
+where
+    L: Linearize,
+{
+    type Item = L;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.bits == 0 {
+            self.word += 1;
+            self.bits = *self.row.get(self.word)?;
+        }
+        let bit = self.bits.trailing_zeros() as usize;
+        self.bits &= self.bits - 1;
+        unsafe {
+            // SAFETY: The row only contains bits for indices less than L::LENGTH.
+            Some(L::from_linear_unchecked(self.word * BITS + bit))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.bits.count_ones() as usize
+            + self
+                .row
+                .get(self.word + 1..)
+                .unwrap_or_default()
+                .iter()
+                .map(|w| w.count_ones() as usize)
+                .sum::<usize>();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<L> ExactSizeIterator for Successors<'_, L> where L: Linearize {}
+
+impl<L> Clone for Successors<'_, L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            row: self.row,
+            word: self.word,
+            bits: self.bits,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<L> Iterator for Predecessors<'_, L>
+where
+    L: Linearize,
+{
+    type Item = L;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next < L::LENGTH {
+            let from = self.next;
+            self.next += 1;
+            if self.graph.has(from, self.to) {
+                unsafe {
+                    // SAFETY: from < L::LENGTH.
+                    return Some(L::from_linear_unchecked(from));
+                }
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(L::LENGTH - self.next))
+    }
+}
+
+impl<L> Clone for Predecessors<'_, L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            graph: self.graph,
+            to: self.to,
+            next: self.next,
+        }
+    }
+}
@@ -0,0 +1,213 @@
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+use {
+    crate::{map::iters::Iter, Linearize, LinearizeExt, StaticMap},
+    core::{
+        cell::OnceCell,
+        fmt::{Debug, Formatter},
+        iter::FusedIterator,
+    },
+};
+
+macro_rules! lazy_map {
+    (
+        $(#[$meta:meta])*
+        $name:ident,
+        $iter:ident,
+        $cell:ident,
+        $example:literal,
+    ) => {
+        $(#[$meta])*
+        ///
+        /// Each slot of the map is initialized on first access. Therefore,
+        /// [`new`](Self::new) is a `const fn`.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = $example]
+        /// ```
+        pub struct $name<L, T, F = fn(L) -> T>
+        where
+            L: Linearize,
+        {
+            slots: $cell<StaticMap<L, $cell<T>>>,
+            init: F,
+        }
+
+        impl<L, T, F> $name<L, T, F>
+        where
+            L: Linearize,
+        {
+            /// Creates a new map that computes the value of each key with `init`.
+            #[inline]
+            pub const fn new(init: F) -> Self {
+                Self {
+                    slots: $cell::new(),
+                    init,
+                }
+            }
+
+            /// Returns the initializer of this map.
+            #[inline]
+            pub fn initializer(&self) -> &F {
+                &self.init
+            }
+
+            /// Returns the value of `key`, computing it if it has not been computed yet.
+            #[inline]
+            pub fn get(&self, key: &L) -> &T
+            where
+                F: Fn(L) -> T,
+            {
+                let key = key.linearized();
+                self.slots()[key].get_or_init(|| (self.init)(key.delinearize()))
+            }
+
+            /// Returns the value of `key` if it has already been computed.
+            #[inline]
+            pub fn get_if_init(&self, key: &L) -> Option<&T> {
+                self.slots.get()?[key].get()
+            }
+
+            /// Returns whether the value of `key` has already been computed.
+            #[inline]
+            pub fn is_init(&self, key: &L) -> bool {
+                self.get_if_init(key).is_some()
+            }
+
+            /// Returns an iterator over the keys whose values have already been
+            /// computed.
+            #[inline]
+            pub fn iter_initialized(&self) -> $iter<'_, L, T> {
+                $iter {
+                    iter: self.slots.get().map(|s| s.iter()),
+                }
+            }
+
+            /// Computes the values of all keys that have not been computed yet.
+            #[inline]
+            pub fn force_all(&self)
+            where
+                F: Fn(L) -> T,
+            {
+                for (k, v) in self.slots() {
+                    v.get_or_init(|| (self.init)(k));
+                }
+            }
+
+            /// Forgets all computed values.
+            #[inline]
+            pub fn reset(&mut self) {
+                self.slots.take();
+            }
+
+            /// Converts this map into a [`StaticMap`], computing all values that have
+            /// not been computed yet.
+            pub fn into_static_map(self) -> StaticMap<L, T>
+            where
+                F: Fn(L) -> T,
+            {
+                let init = self.init;
+                match self.slots.into_inner() {
+                    Some(slots) => {
+                        let mut slots = slots.into_values();
+                        StaticMap::from_fn(|l| match slots.next().and_then($cell::into_inner) {
+                            Some(v) => v,
+                            None => init(l),
+                        })
+                    }
+                    None => StaticMap::from_fn(init),
+                }
+            }
+
+            #[inline]
+            fn slots(&self) -> &StaticMap<L, $cell<T>> {
+                self.slots.get_or_init(|| StaticMap::from_fn(|_| $cell::new()))
+            }
+        }
+
+        impl<L, T, F> Debug for $name<L, T, F>
+        where
+            L: Linearize + Debug,
+            T: Debug,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                f.debug_map().entries(self.iter_initialized()).finish()
+            }
+        }
+
+        /// An iterator over the computed values of a lazy map.
+        ///
+        #[doc = concat!("See [`", stringify!($name), "::iter_initialized`].")]
+        pub struct $iter<'a, L, T>
+        where
+            L: Linearize,
+        {
+            iter: Option<Iter<'a, L, $cell<T>>>,
+        }
+
+        impl<'a, L, T> Iterator for $iter<'a, L, T>
+        where
+            L: Linearize,
+        {
+            type Item = (L, &'a T);
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let iter = self.iter.as_mut()?;
+                iter.find_map(|(k, v)| v.get().map(|v| (k, v)))
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let upper = self.iter.as_ref().map(|i| i.len()).unwrap_or_default();
+                (0, Some(upper))
+            }
+        }
+
+        impl<L, T> FusedIterator for $iter<'_, L, T> where L: Linearize {}
+    };
+}
+
+lazy_map! {
+    /// A map that computes its values on demand.
+    ///
+    /// Each value is computed by calling the initializer the first time the value is
+    /// accessed and cached afterwards. This type is not thread safe. See
+    /// [`SyncLazyStaticMap`](crate::SyncLazyStaticMap) for a thread-safe version.
+    LazyStaticMap,
+    IterInitialized,
+    OnceCell,
+    "# use linearize::LazyStaticMap;
+# use std::cell::Cell;
+let calls = Cell::new(0);
+let map = LazyStaticMap::new(|b: bool| {
+    calls.set(calls.get() + 1);
+    b as u8
+});
+assert_eq!(map.get_if_init(&true), None);
+assert_eq!(*map.get(&true), 1);
+assert_eq!(*map.get(&true), 1);
+assert_eq!(calls.get(), 1);
+assert_eq!(map.iter_initialized().collect::<Vec<_>>(), [(true, &1)]);",
+}
+
+#[cfg(feature = "std")]
+lazy_map! {
+    /// A thread-safe map that computes its values on demand.
+    ///
+    /// Each value is computed by calling the initializer the first time the value is
+    /// accessed and cached afterwards. If multiple threads access an uncomputed value
+    /// concurrently, the initializer is called only once.
+    ///
+    /// This type is only available with the `std` feature.
+    SyncLazyStaticMap,
+    SyncIterInitialized,
+    OnceLock,
+    "# use linearize::SyncLazyStaticMap;
+# use std::cmp::Ordering;
+static NAMES: SyncLazyStaticMap<Ordering, String> =
+    SyncLazyStaticMap::new(|o| format!(\"{o:?}\").to_lowercase());
+assert_eq!(NAMES.get(&Ordering::Less), \"less\");",
+}
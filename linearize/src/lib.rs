@@ -61,39 +61,117 @@
 //! This crate provides the following features:
 //!
 //! - `alloc`: Adds a dependency on the `alloc` crate. This implements additional traits
-//!   for the map types.
-//! - `std`: Adds a dependency on the `std` crate.
+//!   for the map types and provides [StaticGraph], [BoxedStaticMap], [PagedStaticMap],
+//!   [TrackedStaticMap], and constructors that build maps directly on the heap.
+//! - `std`: Adds a dependency on the `std` crate and provides [SyncLazyStaticMap].
 //! - `derive`: Provides the [Linearize](linearize_derive::Linearize) derive macro.
 //! - `serde-1`: Implements `Serialize` and `Deserialize` from serde 1.x for the map types.
 //! - `arbitrary-1`: Implements `Arbitrary` from arbitrary 1.x for the map types.
+//! - `borsh-1`: Implements `BorshSerialize` and `BorshDeserialize` from borsh 1.x for the
+//!   map types. A map is encoded as its `L::LENGTH` values in the order of their keys
+//!   without a length prefix, the same encoding as `[T; L::LENGTH]`.
+//! - `borsh-1-schema`: Implements `BorshSchema` from borsh 1.x for the map types. The
+//!   declaration contains the [`type_name`](core::any::type_name) of the key type, for
+//!   example `StaticMap<bool, u8>`, so the key type does not need to implement
+//!   `BorshSchema`. This feature enables the `unstable__schema` feature of borsh and
+//!   follows its unstable schema API. It is therefore exempt from semver guarantees.
 //! - `bytemuck-1`: Implements `NoUninit`, `Zeroable`, and `AnyBitPattern` from bytemuck 1.x for the map types.
+//!   Also enables the `bytemuck` attribute of the [Linearize](linearize_derive::Linearize)
+//!   derive macro.
 //! - `rand-0_8`: Implements various distributions from rand 0.8.x for the map types.
 //! - `rand-0_9`: Implements various distributions from rand 0.9.x for the map types.
+//! - `zerocopy-0_8`: Implements `FromBytes`, `FromZeros`, `TryFromBytes`, `IntoBytes`,
+//!   `Immutable`, and `KnownLayout` from zerocopy 0.8.x for the map types. Derive
+//!   `TryFromBytes` for a fieldless `#[repr(uN)]` enum to read maps with values of that
+//!   enum from bytes. Also enables the `zerocopy` attribute of the
+//!   [Linearize](linearize_derive::Linearize) derive macro, which checks that the
+//!   validation of the derived `TryFromBytes` agrees with `Linearize`.
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(target_has_atomic = "64")]
+mod atomic;
+mod bits;
+#[cfg(feature = "alloc")]
+mod boxed;
+mod builder;
 mod copy_map;
+mod counter;
+mod dfa;
 mod foreign;
+#[cfg(feature = "alloc")]
+mod graph;
 mod impls;
+mod lazy;
 mod linearized;
 mod r#macro;
 mod map;
+mod matrix;
+#[cfg(feature = "alloc")]
+mod paged;
+mod patch;
+mod permutation;
+mod product;
+mod range_coder;
 mod storage;
+#[cfg(feature = "alloc")]
+mod tracked;
 mod variants;
 
-use crate::{
-    sealed::Sealed,
-    storage::{CopyStorage, Storage},
-    variants::Variants,
-};
+#[cfg(target_has_atomic = "64")]
+pub use atomic::{Atomic, AtomicAdd, CachePadded, StaticAtomicMap};
+#[cfg(feature = "alloc")]
+pub use boxed::BoxedStaticMap;
+#[cfg(feature = "bytemuck-1")]
+#[doc(hidden)]
+pub use bytemuck_1 as __bytemuck_1;
 #[cfg(feature = "serde-1")]
 pub use foreign::serde_1;
+#[cfg(feature = "alloc")]
+pub use graph::{Cyclic, StaticGraph};
+#[cfg(feature = "std")]
+pub use lazy::SyncLazyStaticMap;
 #[cfg(feature = "derive")]
 pub use linearize_derive::Linearize;
+#[cfg(feature = "alloc")]
+pub use paged::PagedStaticMap;
 #[doc(hidden)]
 pub use r#macro::Builder;
-pub use {copy_map::StaticCopyMap, linearized::Linearized, map::StaticMap};
+#[cfg(feature = "alloc")]
+pub use tracked::TrackedStaticMap;
+#[cfg(feature = "zerocopy-0_8")]
+#[doc(hidden)]
+pub use zerocopy_0_8 as __zerocopy_0_8;
+use {
+    crate::{
+        sealed::Sealed,
+        storage::{CopyStorage, Storage},
+        variants::Variants,
+    },
+    core::ops::RangeBounds,
+};
+pub use {
+    bits::{bit_width, BitError, BitReader, BitWriter},
+    builder::{Incomplete, StaticMapBuilder},
+    copy_map::StaticCopyMap,
+    counter::StaticCounter,
+    dfa::{Dfa, Dot},
+    lazy::LazyStaticMap,
+    linearized::Linearized,
+    map::{
+        slice::{StaticMapSlice, StaticMapSliceMut},
+        StaticMap,
+    },
+    matrix::{StaticMatrix, StaticMatrixColumn, StaticMatrixColumnMut},
+    patch::StaticMapPatch,
+    permutation::{Collision, Permutation},
+    product::LinearizeProduct,
+    range_coder::{
+        AdaptiveModel, ByteSink, FrequencyModel, RangeDecoder, RangeEncoder, RangeError,
+        StaticModel,
+    },
+};
 
 /// Types whose values can be enumerated.
 ///
@@ -158,10 +236,132 @@ pub trait LinearizeExt: Linearize + Sealed {
     where
         Self: Sized;
 
+    /// Returns an iterator over all values of this type that are contained in `range`.
+    ///
+    /// Values are ordered by their linearization. If the start of the range is after its
+    /// end, the iterator is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # use linearize::LinearizeExt;
+    /// let mut variants = Ordering::variants_in(Ordering::Equal..);
+    /// assert_eq!(variants.next(), Some(Ordering::Equal));
+    /// assert_eq!(variants.next(), Some(Ordering::Greater));
+    /// assert_eq!(variants.next(), None);
+    /// ```
+    fn variants_in(range: impl RangeBounds<Self>) -> Variants<Self>
+    where
+        Self: Sized;
+
     /// Linearizes this value and stores the value in a [Linearized] object.
     ///
     /// See the documentation of [Linearized] for why this might be useful.
     fn linearized(&self) -> Linearized<Self>;
+
+    /// Returns the first value of this type or `None` if the type is empty.
+    ///
+    /// This is the value whose linearization is `0`.
+    fn first() -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Returns the last value of this type or `None` if the type is empty.
+    ///
+    /// This is the value whose linearization is `Self::LENGTH - 1`.
+    fn last() -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Returns the next value or `None` if this is the last value.
+    ///
+    /// Values are ordered by their linearization.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # use linearize::LinearizeExt;
+    /// assert_eq!(Ordering::Less.succ(), Some(Ordering::Equal));
+    /// assert_eq!(Ordering::Greater.succ(), None);
+    /// ```
+    fn succ(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Returns the previous value or `None` if this is the first value.
+    ///
+    /// Values are ordered by their linearization.
+    fn pred(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Returns the next value, wrapping around to the first value after the last value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # use linearize::LinearizeExt;
+    /// assert_eq!(Ordering::Less.wrapping_succ(), Ordering::Equal);
+    /// assert_eq!(Ordering::Greater.wrapping_succ(), Ordering::Less);
+    /// ```
+    fn wrapping_succ(&self) -> Self
+    where
+        Self: Sized;
+
+    /// Returns the previous value, wrapping around to the last value before the first
+    /// value.
+    fn wrapping_pred(&self) -> Self
+    where
+        Self: Sized;
+
+    /// Returns the value `offset` positions after this value or `None` if no such value
+    /// exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # use linearize::LinearizeExt;
+    /// assert_eq!(Ordering::Greater.checked_offset(-2), Some(Ordering::Less));
+    /// assert_eq!(Ordering::Greater.checked_offset(1), None);
+    /// ```
+    fn checked_offset(&self, offset: isize) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Returns the value `offset` positions after this value, wrapping around at the
+    /// boundaries of this type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # use linearize::LinearizeExt;
+    /// assert_eq!(Ordering::Greater.wrapping_offset(2), Ordering::Equal);
+    /// assert_eq!(Ordering::Less.wrapping_offset(-4), Ordering::Greater);
+    /// ```
+    fn wrapping_offset(&self, offset: isize) -> Self
+    where
+        Self: Sized;
+
+    /// Returns the number of positions from this value to `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit into an `isize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # use linearize::LinearizeExt;
+    /// assert_eq!(Ordering::Less.distance(&Ordering::Greater), 2);
+    /// assert_eq!(Ordering::distance(&Ordering::Greater, &Ordering::Less), -2);
+    /// ```
+    fn distance(&self, other: &Self) -> isize;
 }
 
 impl<T> LinearizeExt for T
@@ -185,9 +385,78 @@ where
         Variants::new()
     }
 
+    fn variants_in(range: impl RangeBounds<Self>) -> Variants<Self>
+    where
+        Self: Sized,
+    {
+        Variants::in_range(range)
+    }
+
     fn linearized(&self) -> Linearized<Self> {
         Linearized::new(self)
     }
+
+    fn first() -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Linearized::first().map(Linearized::delinearize)
+    }
+
+    fn last() -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Linearized::last().map(Linearized::delinearize)
+    }
+
+    fn succ(&self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.linearized().succ().map(Linearized::delinearize)
+    }
+
+    fn pred(&self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.linearized().pred().map(Linearized::delinearize)
+    }
+
+    fn wrapping_succ(&self) -> Self
+    where
+        Self: Sized,
+    {
+        self.linearized().wrapping_succ().delinearize()
+    }
+
+    fn wrapping_pred(&self) -> Self
+    where
+        Self: Sized,
+    {
+        self.linearized().wrapping_pred().delinearize()
+    }
+
+    fn checked_offset(&self, offset: isize) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.linearized()
+            .checked_offset(offset)
+            .map(Linearized::delinearize)
+    }
+
+    fn wrapping_offset(&self, offset: isize) -> Self
+    where
+        Self: Sized,
+    {
+        self.linearized().wrapping_offset(offset).delinearize()
+    }
+
+    fn distance(&self, other: &Self) -> isize {
+        self.linearized().distance(other.linearized())
+    }
 }
 
 impl<T> Sealed for T where T: Linearize + ?Sized {}
@@ -200,8 +469,23 @@ pub mod iter {
     //! All iterators exposed by this crate.
     //!
     //! This module exists only to keep the top-level namespace clean.
+    #[cfg(feature = "alloc")]
+    pub use crate::graph::{Predecessors, Successors};
+    #[cfg(feature = "std")]
+    pub use crate::lazy::SyncIterInitialized;
+    #[cfg(feature = "alloc")]
+    pub use crate::paged::{AllocatedIter, AllocatedIterMut, PagedIter};
+    #[cfg(feature = "alloc")]
+    pub use crate::tracked::{ChangesSince, DirtyKeys, TrackedIterMut, TrackedValuesMut};
     pub use crate::{
+        builder::MissingKeys,
+        counter::MostCommon,
+        lazy::IterInitialized,
         map::iters::{IntoIter, Iter, IterMut},
+        matrix::{ColumnIter, ColumnIterMut},
+        patch::{Diff, PatchIter},
+        permutation::{Cycle, Cycles, Permutations},
+        range_coder::Decode,
         variants::Variants,
     };
 }
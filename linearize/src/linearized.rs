@@ -93,6 +93,25 @@ where
         }
     }
 
+    /// Wraps an index if it is less than `L::LENGTH`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::{LinearizeExt, Linearized};
+    /// assert_eq!(Linearized::<bool>::from_index(1), Some(true.linearized()));
+    /// assert_eq!(Linearized::<bool>::from_index(2), None);
+    /// ```
+    pub fn from_index(index: usize) -> Option<Self>
+    where
+        L: Linearize,
+    {
+        (index < L::LENGTH).then(|| unsafe {
+            // SAFETY: index < L::LENGTH.
+            Self::new_unchecked(index)
+        })
+    }
+
     /// Returns the linearized value.
     ///
     /// This function returns the output of [linearize](Linearize::linearize) that was
@@ -135,6 +154,187 @@ where
             L::from_linear_unchecked(self.index)
         }
     }
+
+    /// Returns the first value of `L` or `None` if `L` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::{LinearizeExt, Linearized};
+    /// assert_eq!(Linearized::<bool>::first(), Some(false.linearized()));
+    /// ```
+    pub fn first() -> Option<Self>
+    where
+        L: Linearize,
+    {
+        (L::LENGTH > 0).then(|| unsafe {
+            // SAFETY: 0 < L::LENGTH.
+            Self::new_unchecked(0)
+        })
+    }
+
+    /// Returns the last value of `L` or `None` if `L` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::{LinearizeExt, Linearized};
+    /// assert_eq!(Linearized::<bool>::last(), Some(true.linearized()));
+    /// ```
+    pub fn last() -> Option<Self>
+    where
+        L: Linearize,
+    {
+        L::LENGTH.checked_sub(1).map(|index| unsafe {
+            // SAFETY: index = L::LENGTH - 1.
+            Self::new_unchecked(index)
+        })
+    }
+
+    /// Returns the next value or `None` if this is the last value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::LinearizeExt;
+    /// assert_eq!(false.linearized().succ(), Some(true.linearized()));
+    /// assert_eq!(true.linearized().succ(), None);
+    /// ```
+    pub fn succ(self) -> Option<Self>
+    where
+        L: Linearize,
+    {
+        self.checked_offset(1)
+    }
+
+    /// Returns the previous value or `None` if this is the first value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::LinearizeExt;
+    /// assert_eq!(true.linearized().pred(), Some(false.linearized()));
+    /// assert_eq!(false.linearized().pred(), None);
+    /// ```
+    pub fn pred(self) -> Option<Self>
+    where
+        L: Linearize,
+    {
+        self.checked_offset(-1)
+    }
+
+    /// Returns the next value, wrapping around to the first value after the last value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::LinearizeExt;
+    /// assert_eq!(true.linearized().wrapping_succ(), false.linearized());
+    /// ```
+    pub fn wrapping_succ(self) -> Self
+    where
+        L: Linearize,
+    {
+        self.wrapping_offset(1)
+    }
+
+    /// Returns the previous value, wrapping around to the last value before the first
+    /// value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::LinearizeExt;
+    /// assert_eq!(false.linearized().wrapping_pred(), true.linearized());
+    /// ```
+    pub fn wrapping_pred(self) -> Self
+    where
+        L: Linearize,
+    {
+        self.wrapping_offset(-1)
+    }
+
+    /// Returns the value `offset` positions after this value or `None` if no such value
+    /// exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::LinearizeExt;
+    /// assert_eq!(10u8.linearized().checked_offset(-3), Some(7u8.linearized()));
+    /// assert_eq!(10u8.linearized().checked_offset(-11), None);
+    /// assert_eq!(10u8.linearized().checked_offset(246), None);
+    /// ```
+    pub fn checked_offset(self, offset: isize) -> Option<Self>
+    where
+        L: Linearize,
+    {
+        let index = self.index.checked_add_signed(offset)?;
+        (index < L::LENGTH).then(|| unsafe {
+            // SAFETY: index < L::LENGTH.
+            Self::new_unchecked(index)
+        })
+    }
+
+    /// Returns the value `offset` positions after this value, wrapping around at the
+    /// boundaries of `L`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::LinearizeExt;
+    /// assert_eq!(10u8.linearized().wrapping_offset(-11), 255u8.linearized());
+    /// assert_eq!(10u8.linearized().wrapping_offset(246), 0u8.linearized());
+    /// ```
+    pub fn wrapping_offset(self, offset: isize) -> Self
+    where
+        L: Linearize,
+    {
+        // NOTE: L::LENGTH > 0 since self is a value of L.
+        let len = L::LENGTH;
+        let distance = offset.unsigned_abs() % len;
+        let index = if offset >= 0 {
+            let remaining = len - self.index;
+            if distance < remaining {
+                self.index + distance
+            } else {
+                distance - remaining
+            }
+        } else if distance <= self.index {
+            self.index - distance
+        } else {
+            len - (distance - self.index)
+        };
+        unsafe {
+            // SAFETY: By the computation above, index < len = L::LENGTH.
+            Self::new_unchecked(index)
+        }
+    }
+
+    /// Returns the number of positions from this value to `other`.
+    ///
+    /// This is the inverse of [`checked_offset`](Self::checked_offset).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit into an `isize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::LinearizeExt;
+    /// assert_eq!(3u8.linearized().distance(10u8.linearized()), 7);
+    /// assert_eq!(10u8.linearized().distance(3u8.linearized()), -7);
+    /// ```
+    pub fn distance(self, other: Self) -> isize {
+        if other.index >= self.index {
+            isize::try_from(other.index - self.index).expect("distance overflows isize")
+        } else {
+            0isize
+                .checked_sub_unsigned(self.index - other.index)
+                .expect("distance overflows isize")
+        }
+    }
 }
 
 impl<L> Copy for Linearized<L> where L: ?Sized {}
@@ -1,9 +1,12 @@
 use {
     crate::{
         copy_map::StaticCopyMap,
-        map::iters::{IntoIter, IntoValues, Iter, IterMut},
+        map::{
+            iters::{IntoIter, IntoValues, Iter, IterMut},
+            slice::{StaticMapSlice, StaticMapSliceMut},
+        },
         storage::Storage,
-        variants::Variants,
+        variants::{linear_range, Variants},
         Linearize, LinearizeExt, Linearized,
     },
     core::{
@@ -13,11 +16,12 @@ use {
         fmt::{Debug, Formatter},
         hash::{Hash, Hasher},
         mem,
-        ops::{Deref, DerefMut, Index, IndexMut},
+        ops::{Deref, DerefMut, Index, IndexMut, RangeBounds},
     },
 };
 
 pub(crate) mod iters;
+pub(crate) mod slice;
 
 /// An array-backed map with complex keys.
 ///
@@ -162,6 +166,16 @@ pub(crate) mod iters;
 ///
 /// Use [`StaticCopyMap`] if `T` is `Copy` and you want the map to be `Copy` as well.
 #[repr(transparent)]
+#[cfg_attr(
+    feature = "zerocopy-0_8",
+    derive(
+        zerocopy_0_8::FromBytes,
+        zerocopy_0_8::IntoBytes,
+        zerocopy_0_8::Immutable,
+        zerocopy_0_8::KnownLayout,
+    ),
+    zerocopy(crate = "::zerocopy_0_8")
+)]
 pub struct StaticMap<L, T>(
     /// The underlying `[T; L::LENGTH]`.
     pub <L as Linearize>::Storage<T>,
@@ -610,6 +624,75 @@ where
         IterMut::new(&mut self.0)
     }
 
+    /// Returns a view of the entries whose keys are contained in `range`.
+    ///
+    /// Keys are ordered by their linearization. If the start of the range is after its
+    /// end, the view is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::{static_map, Linearize, StaticMap};
+    /// #[derive(Linearize, Debug, PartialEq)]
+    /// enum Key {
+    ///     A,
+    ///     B,
+    ///     C,
+    ///     D,
+    /// }
+    ///
+    /// let map: StaticMap<_, u8> = static_map! {
+    ///     Key::A => 0,
+    ///     Key::B => 1,
+    ///     Key::C => 2,
+    ///     Key::D => 3,
+    /// };
+    /// let range = map.range(Key::B..=Key::C);
+    /// assert_eq!(range.as_slice(), [1, 2]);
+    /// assert_eq!(range[Key::C], 2);
+    /// let mut iter = range.iter();
+    /// assert_eq!(iter.next(), Some((Key::B, &1)));
+    /// assert_eq!(iter.next(), Some((Key::C, &2)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn range(&self, range: impl RangeBounds<L>) -> StaticMapSlice<'_, L, T>
+    where
+        L: Sized,
+    {
+        unsafe {
+            // SAFETY: linear_range returns a range contained in 0..L::LENGTH.
+            StaticMapSlice::new(&self.0, linear_range(range))
+        }
+    }
+
+    /// Returns a mutable view of the entries whose keys are contained in `range`.
+    ///
+    /// Keys are ordered by their linearization. If the start of the range is after its
+    /// end, the view is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::{static_map, StaticMap};
+    /// let mut map: StaticMap<u8, u8> = StaticMap::default();
+    /// map.range_mut(10..20).fill(1);
+    /// assert_eq!(map[9], 0);
+    /// assert_eq!(map[10], 1);
+    /// assert_eq!(map[19], 1);
+    /// assert_eq!(map[20], 0);
+    /// ```
+    #[inline]
+    pub fn range_mut(&mut self, range: impl RangeBounds<L>) -> StaticMapSliceMut<'_, L, T>
+    where
+        L: Sized,
+    {
+        unsafe {
+            // SAFETY: linear_range returns a range contained in 0..L::LENGTH.
+            StaticMapSliceMut::new(&mut self.0, linear_range(range))
+        }
+    }
+
     /// Consumes the map and returns an iterator over the values.
     ///
     /// # Example
@@ -633,6 +716,33 @@ where
     }
 }
 
+impl<A, B, T> StaticMap<A, StaticMap<B, T>>
+where
+    A: Linearize,
+    B: Linearize,
+{
+    /// Swaps the keys of this nested map.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::StaticMap;
+    /// let map = StaticMap::from_fn(|a: bool| StaticMap::from_fn(|b: u8| (a, b)));
+    /// let map: StaticMap<u8, StaticMap<bool, _>> = map.transpose();
+    /// assert_eq!(map[3][true], (true, 3));
+    /// ```
+    pub fn transpose(self) -> StaticMap<B, StaticMap<A, T>> {
+        let mut rows = self.map_values(|row| row.into_values());
+        StaticMap(<B::Storage<_> as Storage<B, _>>::from_fn(|_| {
+            StaticMap(<A::Storage<T> as Storage<A, T>>::from_fn(|a| {
+                // The outer callback is invoked exactly B::LENGTH times and each row
+                // yields exactly B::LENGTH values.
+                rows.0.as_mut()[a].next().unwrap()
+            }))
+        }))
+    }
+}
+
 impl<L, T> Deref for StaticMap<L, T>
 where
     L: Linearize + ?Sized,
@@ -21,8 +21,18 @@ where
     T: 'a,
 {
     pub(super) fn new(storage: &'a L::Storage<T>) -> Self {
+        unsafe {
+            // SAFETY: The range is 0..L::LENGTH.
+            Self::new_range(storage, 0..L::LENGTH)
+        }
+    }
+
+    /// # Safety
+    ///
+    /// The range must be contained in 0..L::LENGTH.
+    pub(super) unsafe fn new_range(storage: &'a L::Storage<T>, iter: Range<usize>) -> Self {
         Self {
-            iter: 0..L::LENGTH,
+            iter,
             storage: <L::Storage<T> as AsRef<[T]>>::as_ref(storage).as_ptr(),
             _phantom: Default::default(),
         }
@@ -151,8 +161,18 @@ where
     T: 'a,
 {
     pub(super) fn new(storage: &'a mut L::Storage<T>) -> Self {
+        unsafe {
+            // SAFETY: The range is 0..L::LENGTH.
+            Self::new_range(storage, 0..L::LENGTH)
+        }
+    }
+
+    /// # Safety
+    ///
+    /// The range must be contained in 0..L::LENGTH.
+    pub(super) unsafe fn new_range(storage: &'a mut L::Storage<T>, iter: Range<usize>) -> Self {
         Self {
-            iter: 0..L::LENGTH,
+            iter,
             storage: <L::Storage<T> as AsMut<[T]>>::as_mut(storage).as_mut_ptr(),
             _phantom: Default::default(),
         }
@@ -240,11 +260,11 @@ where
         self.iter.count()
     }
 
-    fn last(self) -> Option<Self::Item>
+    fn last(mut self) -> Option<Self::Item>
     where
         Self: Sized,
     {
-        self.iter.last().map(|(i, v)| {
+        self.iter.next_back().map(|(i, v)| {
             let k = unsafe {
                 // SAFETY: i was returned by self.iter
                 Self::key(i)
@@ -0,0 +1,405 @@
+use {
+    crate::{
+        map::iters::{Iter, IterMut},
+        variants::Variants,
+        Linearize,
+    },
+    core::{
+        fmt::{Debug, Formatter},
+        ops::{Deref, DerefMut, Index, IndexMut, Range},
+    },
+};
+
+/// A view of a contiguous range of keys of a [`StaticMap`](crate::StaticMap).
+///
+/// Construct it with [`StaticMap::range`](crate::StaticMap::range).
+///
+/// This type dereferences to the slice of values in the range.
+pub struct StaticMapSlice<'a, L, T>
+where
+    L: Linearize,
+{
+    storage: &'a L::Storage<T>,
+    start: usize,
+    end: usize,
+}
+
+/// A mutable view of a contiguous range of keys of a [`StaticMap`](crate::StaticMap).
+///
+/// Construct it with [`StaticMap::range_mut`](crate::StaticMap::range_mut).
+///
+/// This type dereferences to the slice of values in the range.
+pub struct StaticMapSliceMut<'a, L, T>
+where
+    L: Linearize,
+{
+    storage: &'a mut L::Storage<T>,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, L, T> StaticMapSlice<'a, L, T>
+where
+    L: Linearize,
+    T: 'a,
+{
+    /// # Safety
+    ///
+    /// The range must be contained in 0..L::LENGTH.
+    pub(super) unsafe fn new(storage: &'a L::Storage<T>, range: Range<usize>) -> Self {
+        Self {
+            storage,
+            start: range.start,
+            end: range.end,
+        }
+    }
+
+    /// Returns the values in this range.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [T] {
+        &<L::Storage<T> as AsRef<[T]>>::as_ref(self.storage)[self.start..self.end]
+    }
+
+    /// Returns a reference to the value of `key` or `None` if the key is not in this
+    /// range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # use linearize::StaticMap;
+    /// let map = StaticMap::from_fn(|o: Ordering| o as i8);
+    /// let range = map.range(Ordering::Equal..);
+    /// assert_eq!(range.get(&Ordering::Greater), Some(&1));
+    /// assert_eq!(range.get(&Ordering::Less), None);
+    /// ```
+    #[inline]
+    pub fn get(&self, key: &L) -> Option<&'a T> {
+        let idx = key.linearize();
+        (self.start..self.end)
+            .contains(&idx)
+            .then(|| &<L::Storage<T> as AsRef<[T]>>::as_ref(self.storage)[idx])
+    }
+
+    /// Returns an iterator over the keys in this range.
+    #[inline]
+    pub fn keys(&self) -> Variants<L> {
+        Variants::from_linear_range(self.start..self.end)
+    }
+
+    /// Returns an iterator over references to the values in this range.
+    #[inline]
+    pub fn values(&self) -> core::slice::Iter<'a, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator over references to the entries in this range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # use linearize::StaticMap;
+    /// let map = StaticMap::from_fn(|o: Ordering| o as i8);
+    /// let mut iter = map.range(..Ordering::Greater).iter();
+    /// assert_eq!(iter.next(), Some((Ordering::Less, &-1)));
+    /// assert_eq!(iter.next(), Some((Ordering::Equal, &0)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'a, L, T> {
+        unsafe {
+            // SAFETY: The range is contained in 0..L::LENGTH by the invariants of this
+            // type.
+            Iter::new_range(self.storage, self.start..self.end)
+        }
+    }
+}
+
+impl<'a, L, T> StaticMapSliceMut<'a, L, T>
+where
+    L: Linearize,
+    T: 'a,
+{
+    /// # Safety
+    ///
+    /// The range must be contained in 0..L::LENGTH.
+    pub(super) unsafe fn new(storage: &'a mut L::Storage<T>, range: Range<usize>) -> Self {
+        Self {
+            storage,
+            start: range.start,
+            end: range.end,
+        }
+    }
+
+    /// Returns the values in this range.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.storage.as_ref()[self.start..self.end]
+    }
+
+    /// Returns the values in this range.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.storage.as_mut()[self.start..self.end]
+    }
+
+    /// Consumes the view and returns the values in this range.
+    #[inline]
+    pub fn into_mut_slice(self) -> &'a mut [T] {
+        &mut <L::Storage<T> as AsMut<[T]>>::as_mut(self.storage)[self.start..self.end]
+    }
+
+    /// Returns a reference to the value of `key` or `None` if the key is not in this
+    /// range.
+    #[inline]
+    pub fn get(&self, key: &L) -> Option<&T> {
+        let idx = key.linearize();
+        (self.start..self.end)
+            .contains(&idx)
+            .then(|| &self.storage.as_ref()[idx])
+    }
+
+    /// Returns a mutable reference to the value of `key` or `None` if the key is not in
+    /// this range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # use linearize::StaticMap;
+    /// let mut map = StaticMap::from_fn(|o: Ordering| o as i8);
+    /// let mut range = map.range_mut(Ordering::Equal..);
+    /// *range.get_mut(&Ordering::Greater).unwrap() = 2;
+    /// assert!(range.get_mut(&Ordering::Less).is_none());
+    /// assert_eq!(map[Ordering::Greater], 2);
+    /// ```
+    #[inline]
+    pub fn get_mut(&mut self, key: &L) -> Option<&mut T> {
+        let idx = key.linearize();
+        (self.start..self.end)
+            .contains(&idx)
+            .then(|| &mut self.storage.as_mut()[idx])
+    }
+
+    /// Returns an iterator over the keys in this range.
+    #[inline]
+    pub fn keys(&self) -> Variants<L> {
+        Variants::from_linear_range(self.start..self.end)
+    }
+
+    /// Returns an iterator over references to the values in this range.
+    #[inline]
+    pub fn values(&self) -> core::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator over mutable references to the values in this range.
+    #[inline]
+    pub fn values_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// Returns an iterator over references to the entries in this range.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, L, T> {
+        unsafe {
+            // SAFETY: The range is contained in 0..L::LENGTH by the invariants of this
+            // type.
+            Iter::new_range(self.storage, self.start..self.end)
+        }
+    }
+
+    /// Returns an iterator over mutable references to the entries in this range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # use linearize::StaticMap;
+    /// let mut map = StaticMap::from_fn(|o: Ordering| o as i8);
+    /// for (_, v) in map.range_mut(Ordering::Equal..).iter_mut() {
+    ///     *v += 10;
+    /// }
+    /// assert_eq!(map[Ordering::Less], -1);
+    /// assert_eq!(map[Ordering::Equal], 10);
+    /// assert_eq!(map[Ordering::Greater], 11);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, L, T> {
+        unsafe {
+            // SAFETY: The range is contained in 0..L::LENGTH by the invariants of this
+            // type.
+            IterMut::new_range(self.storage, self.start..self.end)
+        }
+    }
+}
+
+impl<'a, L, T> Copy for StaticMapSlice<'a, L, T>
+where
+    L: Linearize,
+    T: 'a,
+{
+}
+
+impl<'a, L, T> Clone for StaticMapSlice<'a, L, T>
+where
+    L: Linearize,
+    T: 'a,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, L, T> Deref for StaticMapSlice<'a, L, T>
+where
+    L: Linearize,
+    T: 'a,
+{
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<'a, L, T> Deref for StaticMapSliceMut<'a, L, T>
+where
+    L: Linearize,
+    T: 'a,
+{
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<'a, L, T> DerefMut for StaticMapSliceMut<'a, L, T>
+where
+    L: Linearize,
+    T: 'a,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+macro_rules! impl_index {
+    ($name:ident) => {
+        impl<'a, L, T> Index<&'_ L> for $name<'a, L, T>
+        where
+            L: Linearize,
+            T: 'a,
+        {
+            type Output = T;
+
+            /// # Panics
+            ///
+            /// Panics if the key is not in this range.
+            #[inline]
+            fn index(&self, index: &L) -> &Self::Output {
+                match self.get(index) {
+                    Some(v) => v,
+                    None => panic!("key is not in range"),
+                }
+            }
+        }
+
+        impl<'a, L, T> Index<L> for $name<'a, L, T>
+        where
+            L: Linearize,
+            T: 'a,
+        {
+            type Output = T;
+
+            /// # Panics
+            ///
+            /// Panics if the key is not in this range.
+            #[inline]
+            fn index(&self, index: L) -> &Self::Output {
+                self.index(&index)
+            }
+        }
+
+        impl<'a, L, T> Debug for $name<'a, L, T>
+        where
+            L: Linearize + Debug,
+            T: Debug + 'a,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                f.debug_map().entries(self.iter()).finish()
+            }
+        }
+    };
+}
+
+impl_index!(StaticMapSlice);
+impl_index!(StaticMapSliceMut);
+
+impl<'a, L, T> IndexMut<&'_ L> for StaticMapSliceMut<'a, L, T>
+where
+    L: Linearize,
+    T: 'a,
+{
+    /// # Panics
+    ///
+    /// Panics if the key is not in this range.
+    #[inline]
+    fn index_mut(&mut self, index: &L) -> &mut Self::Output {
+        match self.get_mut(index) {
+            Some(v) => v,
+            None => panic!("key is not in range"),
+        }
+    }
+}
+
+impl<'a, L, T> IndexMut<L> for StaticMapSliceMut<'a, L, T>
+where
+    L: Linearize,
+    T: 'a,
+{
+    /// # Panics
+    ///
+    /// Panics if the key is not in this range.
+    #[inline]
+    fn index_mut(&mut self, index: L) -> &mut Self::Output {
+        self.index_mut(&index)
+    }
+}
+
+impl<'a, L, T> IntoIterator for StaticMapSlice<'a, L, T>
+where
+    L: Linearize,
+    T: 'a,
+{
+    type Item = (L, &'a T);
+    type IntoIter = Iter<'a, L, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, L, T> IntoIterator for StaticMapSliceMut<'a, L, T>
+where
+    L: Linearize,
+    T: 'a,
+{
+    type Item = (L, &'a mut T);
+    type IntoIter = IterMut<'a, L, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        unsafe {
+            // SAFETY: The range is contained in 0..L::LENGTH by the invariants of this
+            // type.
+            IterMut::new_range(self.storage, self.start..self.end)
+        }
+    }
+}
@@ -0,0 +1,735 @@
+use {
+    crate::{
+        map::iters::{Iter, IterMut},
+        Linearize, LinearizeExt, Linearized, StaticMap,
+    },
+    core::{
+        fmt::{Debug, Formatter},
+        hash::{Hash, Hasher},
+        ops::{Add, Index, IndexMut, Mul},
+    },
+};
+
+/// A two-dimensional array-backed map keyed by pairs of linearizable types.
+///
+/// This type is a thin wrapper around `StaticMap<R, StaticMap<C, T>>`. `R` is the type
+/// of the row keys and `C` is the type of the column keys.
+///
+/// # Example
+///
+/// ```rust
+/// # use linearize::StaticMatrix;
+/// let m = StaticMatrix::from_fn(|r: bool, c: u8| r as u32 * c as u32);
+/// assert_eq!(m[(true, 3)], 3);
+/// assert_eq!(m.row(&true)[4], 4);
+/// assert_eq!(m.col(&7)[false], 0);
+/// ```
+#[repr(transparent)]
+pub struct StaticMatrix<R, C, T>(
+    /// The rows of the matrix.
+    pub StaticMap<R, StaticMap<C, T>>,
+)
+where
+    R: Linearize,
+    C: Linearize;
+
+/// A view of a column of a [`StaticMatrix`].
+///
+/// Construct it with [`StaticMatrix::col`].
+pub struct StaticMatrixColumn<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+{
+    rows: &'a StaticMap<R, StaticMap<C, T>>,
+    col: Linearized<C>,
+}
+
+/// A mutable view of a column of a [`StaticMatrix`].
+///
+/// Construct it with [`StaticMatrix::col_mut`].
+pub struct StaticMatrixColumnMut<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+{
+    rows: &'a mut StaticMap<R, StaticMap<C, T>>,
+    col: Linearized<C>,
+}
+
+/// An iterator over references to the entries of a column of a [`StaticMatrix`].
+pub struct ColumnIter<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+{
+    iter: Iter<'a, R, StaticMap<C, T>>,
+    col: Linearized<C>,
+}
+
+/// An iterator over mutable references to the entries of a column of a [`StaticMatrix`].
+pub struct ColumnIterMut<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+{
+    iter: IterMut<'a, R, StaticMap<C, T>>,
+    col: Linearized<C>,
+}
+
+impl<R, C, T> StaticMatrix<R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+{
+    /// Creates a matrix from a callback.
+    ///
+    /// The callback is invoked once for each pair of keys, in row-major order.
+    #[inline]
+    pub fn from_fn(mut cb: impl FnMut(R, C) -> T) -> Self {
+        Self(StaticMap::from_fn(|r: R| {
+            let r = r.linearized();
+            StaticMap::from_fn(|c| cb(r.delinearize(), c))
+        }))
+    }
+
+    /// Creates a matrix from its rows.
+    #[inline]
+    pub fn from_rows(rows: StaticMap<R, StaticMap<C, T>>) -> Self {
+        Self(rows)
+    }
+
+    /// Returns the rows of this matrix.
+    #[inline]
+    pub fn rows(&self) -> &StaticMap<R, StaticMap<C, T>> {
+        &self.0
+    }
+
+    /// Returns the rows of this matrix.
+    #[inline]
+    pub fn rows_mut(&mut self) -> &mut StaticMap<R, StaticMap<C, T>> {
+        &mut self.0
+    }
+
+    /// Converts this matrix into its rows.
+    #[inline]
+    pub fn into_rows(self) -> StaticMap<R, StaticMap<C, T>> {
+        self.0
+    }
+
+    /// Returns a reference to the value at `(r, c)`.
+    #[inline]
+    pub fn get(&self, r: &R, c: &C) -> &T {
+        &self.0[r][c]
+    }
+
+    /// Returns a mutable reference to the value at `(r, c)`.
+    #[inline]
+    pub fn get_mut(&mut self, r: &R, c: &C) -> &mut T {
+        &mut self.0[r][c]
+    }
+
+    /// Returns a reference to the row `r`.
+    #[inline]
+    pub fn row(&self, r: &R) -> &StaticMap<C, T> {
+        &self.0[r]
+    }
+
+    /// Returns a mutable reference to the row `r`.
+    #[inline]
+    pub fn row_mut(&mut self, r: &R) -> &mut StaticMap<C, T> {
+        &mut self.0[r]
+    }
+
+    /// Returns a view of the column `c`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::StaticMatrix;
+    /// let m = StaticMatrix::from_fn(|r: bool, c: bool| (r, c));
+    /// let col = m.col(&true);
+    /// assert_eq!(col[false], (false, true));
+    /// assert_eq!(col.iter().count(), 2);
+    /// ```
+    #[inline]
+    pub fn col(&self, c: &C) -> StaticMatrixColumn<'_, R, C, T> {
+        StaticMatrixColumn {
+            rows: &self.0,
+            col: c.linearized(),
+        }
+    }
+
+    /// Returns a mutable view of the column `c`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::StaticMatrix;
+    /// let mut m = StaticMatrix::from_fn(|_: bool, _: bool| 0);
+    /// for (_, v) in m.col_mut(&true) {
+    ///     *v = 1;
+    /// }
+    /// assert_eq!(m[(false, true)], 1);
+    /// assert_eq!(m[(false, false)], 0);
+    /// ```
+    #[inline]
+    pub fn col_mut(&mut self, c: &C) -> StaticMatrixColumnMut<'_, R, C, T> {
+        StaticMatrixColumnMut {
+            rows: &mut self.0,
+            col: c.linearized(),
+        }
+    }
+
+    /// Swaps rows and columns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::StaticMatrix;
+    /// let m = StaticMatrix::from_fn(|r: bool, c: u8| (r, c));
+    /// let m: StaticMatrix<u8, bool, _> = m.transpose();
+    /// assert_eq!(m[(3, true)], (true, 3));
+    /// ```
+    #[inline]
+    pub fn transpose(self) -> StaticMatrix<C, R, T> {
+        StaticMatrix(self.0.transpose())
+    }
+
+    /// Maps the values of this matrix.
+    #[inline]
+    pub fn map_values<U>(self, mut map: impl FnMut(T) -> U) -> StaticMatrix<R, C, U> {
+        StaticMatrix(self.0.map_values(|row| row.map_values(&mut map)))
+    }
+}
+
+impl<L, T> StaticMatrix<L, L, T>
+where
+    L: Linearize,
+{
+    /// Returns the identity matrix.
+    ///
+    /// The diagonal contains `T::from(true)` and all other values are `T::from(false)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::StaticMatrix;
+    /// let m = StaticMatrix::<bool, bool, f64>::identity();
+    /// assert_eq!(m[(true, true)], 1.0);
+    /// assert_eq!(m[(true, false)], 0.0);
+    /// ```
+    #[inline]
+    pub fn identity() -> Self
+    where
+        T: From<bool>,
+    {
+        Self::from_fn(|r, c| T::from(r.linearize() == c.linearize()))
+    }
+}
+
+impl<'a, R, C, T> StaticMatrixColumn<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+    /// Returns the key of this column.
+    #[inline]
+    pub fn key(&self) -> C {
+        self.col.delinearize()
+    }
+
+    /// Returns a reference to the value in row `r`.
+    #[inline]
+    pub fn get(&self, r: &R) -> &'a T {
+        &self.rows[r][self.col]
+    }
+
+    /// Returns an iterator over the entries of this column.
+    #[inline]
+    pub fn iter(&self) -> ColumnIter<'a, R, C, T> {
+        ColumnIter {
+            iter: self.rows.iter(),
+            col: self.col,
+        }
+    }
+
+    /// Copies this column into a map.
+    #[inline]
+    pub fn to_static_map(&self) -> StaticMap<R, T>
+    where
+        T: Clone,
+    {
+        StaticMap::from_fn(|r| self.get(&r).clone())
+    }
+}
+
+impl<'a, R, C, T> StaticMatrixColumnMut<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+    /// Returns the key of this column.
+    #[inline]
+    pub fn key(&self) -> C {
+        self.col.delinearize()
+    }
+
+    /// Returns a reference to the value in row `r`.
+    #[inline]
+    pub fn get(&self, r: &R) -> &T {
+        &self.rows[r][self.col]
+    }
+
+    /// Returns a mutable reference to the value in row `r`.
+    #[inline]
+    pub fn get_mut(&mut self, r: &R) -> &mut T {
+        &mut self.rows[r][self.col]
+    }
+
+    /// Returns an iterator over the entries of this column.
+    #[inline]
+    pub fn iter(&self) -> ColumnIter<'_, R, C, T> {
+        ColumnIter {
+            iter: self.rows.iter(),
+            col: self.col,
+        }
+    }
+
+    /// Returns an iterator over mutable references to the entries of this column.
+    #[inline]
+    pub fn iter_mut(&mut self) -> ColumnIterMut<'_, R, C, T> {
+        ColumnIterMut {
+            iter: self.rows.iter_mut(),
+            col: self.col,
+        }
+    }
+}
+
+impl<R, C, T> Index<(R, C)> for StaticMatrix<R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (r, c): (R, C)) -> &Self::Output {
+        self.get(&r, &c)
+    }
+}
+
+impl<R, C, T> Index<(&'_ R, &'_ C)> for StaticMatrix<R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (r, c): (&R, &C)) -> &Self::Output {
+        self.get(r, c)
+    }
+}
+
+impl<R, C, T> IndexMut<(R, C)> for StaticMatrix<R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+{
+    #[inline]
+    fn index_mut(&mut self, (r, c): (R, C)) -> &mut Self::Output {
+        self.get_mut(&r, &c)
+    }
+}
+
+impl<R, C, T> IndexMut<(&'_ R, &'_ C)> for StaticMatrix<R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+{
+    #[inline]
+    fn index_mut(&mut self, (r, c): (&R, &C)) -> &mut Self::Output {
+        self.get_mut(r, c)
+    }
+}
+
+impl<'a, R, C, T> Index<&'_ R> for StaticMatrixColumn<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: &R) -> &Self::Output {
+        self.get(index)
+    }
+}
+
+impl<'a, R, C, T> Index<R> for StaticMatrixColumn<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: R) -> &Self::Output {
+        self.get(&index)
+    }
+}
+
+impl<'a, R, C, T> Index<&'_ R> for StaticMatrixColumnMut<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: &R) -> &Self::Output {
+        self.get(index)
+    }
+}
+
+impl<'a, R, C, T> Index<R> for StaticMatrixColumnMut<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: R) -> &Self::Output {
+        self.get(&index)
+    }
+}
+
+impl<'a, R, C, T> IndexMut<&'_ R> for StaticMatrixColumnMut<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+    #[inline]
+    fn index_mut(&mut self, index: &R) -> &mut Self::Output {
+        self.get_mut(index)
+    }
+}
+
+impl<'a, R, C, T> IndexMut<R> for StaticMatrixColumnMut<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+    #[inline]
+    fn index_mut(&mut self, index: R) -> &mut Self::Output {
+        self.get_mut(&index)
+    }
+}
+
+impl<'a, R, C, T> Copy for StaticMatrixColumn<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+}
+
+impl<'a, R, C, T> Clone for StaticMatrixColumn<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, R, C, T> IntoIterator for StaticMatrixColumn<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+    type Item = (R, &'a T);
+    type IntoIter = ColumnIter<'a, R, C, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, R, C, T> IntoIterator for StaticMatrixColumnMut<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+    type Item = (R, &'a mut T);
+    type IntoIter = ColumnIterMut<'a, R, C, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        ColumnIterMut {
+            iter: self.rows.iter_mut(),
+            col: self.col,
+        }
+    }
+}
+
+impl<'a, R, C, T> Debug for StaticMatrixColumn<'a, R, C, T>
+where
+    R: Linearize + Debug,
+    C: Linearize,
+    T: Debug + 'a,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, R, C, T> Debug for StaticMatrixColumnMut<'a, R, C, T>
+where
+    R: Linearize + Debug,
+    C: Linearize,
+    T: Debug + 'a,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, R, C, T> Iterator for ColumnIter<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+    type Item = (R, &'a T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let col = self.col;
+        self.iter.next().map(|(r, row)| (r, &row[col]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, R, C, T> DoubleEndedIterator for ColumnIter<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let col = self.col;
+        self.iter.next_back().map(|(r, row)| (r, &row[col]))
+    }
+}
+
+impl<'a, R, C, T> ExactSizeIterator for ColumnIter<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+}
+
+impl<'a, R, C, T> Clone for ColumnIter<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            col: self.col,
+        }
+    }
+}
+
+impl<'a, R, C, T> Iterator for ColumnIterMut<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+    type Item = (R, &'a mut T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let col = self.col;
+        self.iter.next().map(|(r, row)| (r, &mut row[col]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, R, C, T> DoubleEndedIterator for ColumnIterMut<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let col = self.col;
+        self.iter.next_back().map(|(r, row)| (r, &mut row[col]))
+    }
+}
+
+impl<'a, R, C, T> ExactSizeIterator for ColumnIterMut<'a, R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: 'a,
+{
+}
+
+impl<'a, R, K, C, T> Mul<&'a StaticMatrix<K, C, T>> for &'a StaticMatrix<R, K, T>
+where
+    R: Linearize,
+    K: Linearize,
+    C: Linearize,
+    T: Clone + Default + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = StaticMatrix<R, C, T>;
+
+    /// Multiplies two matrices.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::StaticMatrix;
+    /// let a = StaticMatrix::from_fn(|r: bool, k: u8| r as u32 + k as u32);
+    /// let b = StaticMatrix::from_fn(|k: u8, c: bool| if c { k as u32 } else { 1 });
+    /// let m = &a * &b;
+    /// assert_eq!(m[(false, false)], (0..256).sum());
+    /// ```
+    fn mul(self, rhs: &'a StaticMatrix<K, C, T>) -> Self::Output {
+        StaticMatrix::from_fn(|r: R, c: C| {
+            let row = &self.0[r];
+            let c = c.linearized();
+            let mut sum = T::default();
+            for (k, a) in row {
+                sum = sum + a.clone() * rhs.0[k][c].clone();
+            }
+            sum
+        })
+    }
+}
+
+impl<R, K, C, T> Mul<StaticMatrix<K, C, T>> for StaticMatrix<R, K, T>
+where
+    R: Linearize,
+    K: Linearize,
+    C: Linearize,
+    T: Clone + Default + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = StaticMatrix<R, C, T>;
+
+    #[inline]
+    fn mul(self, rhs: StaticMatrix<K, C, T>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<R, C, T> Clone for StaticMatrix<R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<R, C, T> Default for StaticMatrix<R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self(StaticMap::from_fn(|_| StaticMap::default()))
+    }
+}
+
+impl<R, C, T> PartialEq for StaticMatrix<R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<R, C, T> Eq for StaticMatrix<R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: Eq,
+{
+}
+
+impl<R, C, T> Hash for StaticMatrix<R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+    T: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<R, C, T> Debug for StaticMatrix<R, C, T>
+where
+    R: Linearize + Debug,
+    C: Linearize + Debug,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl<R, C, T> From<StaticMap<R, StaticMap<C, T>>> for StaticMatrix<R, C, T>
+where
+    R: Linearize,
+    C: Linearize,
+{
+    #[inline]
+    fn from(rows: StaticMap<R, StaticMap<C, T>>) -> Self {
+        Self(rows)
+    }
+}
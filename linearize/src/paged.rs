@@ -0,0 +1,554 @@
+use {
+    crate::{Linearize, Linearized},
+    alloc::{
+        boxed::Box,
+        collections::{btree_map, BTreeMap},
+    },
+    core::{
+        fmt::{Debug, Formatter},
+        iter::{Enumerate, FusedIterator},
+        marker::PhantomData,
+        mem,
+        ops::{Index, IndexMut},
+        slice,
+    },
+};
+
+/// A sparse map from `L` to `T` that allocates storage lazily.
+///
+/// This type splits the keys `0..L::LENGTH` into pages of
+/// [`PAGE_SIZE`](Self::PAGE_SIZE) consecutive keys. A page is allocated the first time
+/// one of its values is accessed mutably. Until then, all of its values are equal to
+/// the default value of the map. Memory usage therefore scales with the number of
+/// touched pages instead of with `L::LENGTH`.
+///
+/// This makes it possible to use key types that are too large for a [`StaticMap`],
+/// such as `u32`.
+///
+/// This type is only available with the `alloc` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use linearize::PagedStaticMap;
+/// let mut map = PagedStaticMap::<u32, u8>::new();
+/// map[3_000_000_000] = 1;
+/// map[3_000_000_001] = 2;
+/// assert_eq!(map[0], 0);
+/// assert_eq!(map[3_000_000_001], 2);
+/// assert_eq!(map.allocated_pages(), 1);
+/// let allocated: Vec<_> = map.iter_allocated().filter(|(_, v)| **v != 0).collect();
+/// assert_eq!(allocated, [(3_000_000_000, &1), (3_000_000_001, &2)]);
+/// ```
+///
+/// [`StaticMap`]: crate::StaticMap
+pub struct PagedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    default: T,
+    pages: BTreeMap<usize, Box<[T]>>,
+    _phantom: PhantomData<fn() -> L>,
+}
+
+impl<L, T> PagedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    /// The number of keys in each page.
+    pub const PAGE_SIZE: usize = 256;
+
+    /// Creates a new map where every key maps to `T::default()`.
+    #[inline]
+    pub fn new() -> Self
+    where
+        T: Default,
+    {
+        Self::with_default(T::default())
+    }
+
+    /// Creates a new map where every key maps to `default`.
+    #[inline]
+    pub fn with_default(default: T) -> Self {
+        Self {
+            default,
+            pages: BTreeMap::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the value of keys in pages that have not been allocated.
+    #[inline]
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Returns a reference to the value of `key`.
+    ///
+    /// This function never allocates.
+    #[inline]
+    pub fn get(&self, key: &L) -> &T {
+        self.get_linear(key.linearize())
+    }
+
+    /// Returns a mutable reference to the value of `key`.
+    ///
+    /// This function allocates the page of `key` if necessary.
+    #[inline]
+    pub fn get_mut(&mut self, key: &L) -> &mut T
+    where
+        T: Clone,
+    {
+        self.get_linear_mut(key.linearize())
+    }
+
+    /// Sets the value of `key` and returns the previous value.
+    ///
+    /// This function allocates the page of `key` if necessary.
+    #[inline]
+    pub fn set(&mut self, key: &L, value: T) -> T
+    where
+        T: Clone,
+    {
+        mem::replace(self.get_mut(key), value)
+    }
+
+    /// Returns whether the page of `key` has been allocated.
+    #[inline]
+    pub fn is_allocated(&self, key: &L) -> bool {
+        self.pages
+            .contains_key(&(key.linearize() / Self::PAGE_SIZE))
+    }
+
+    /// Returns the number of allocated pages.
+    #[inline]
+    pub fn allocated_pages(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Resets all values to the default value and frees all pages.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.pages.clear();
+    }
+
+    /// Frees all pages whose values are all equal to the default value.
+    pub fn compact(&mut self)
+    where
+        T: PartialEq,
+    {
+        let default = &self.default;
+        self.pages
+            .retain(|_, page| page.iter().any(|v| v != default));
+    }
+
+    /// Returns an iterator over all keys and their values.
+    ///
+    /// This includes keys in pages that have not been allocated.
+    #[inline]
+    pub fn iter(&self) -> PagedIter<'_, L, T> {
+        PagedIter {
+            map: self,
+            next: 0,
+            page: None,
+            page_end: 0,
+        }
+    }
+
+    /// Returns an iterator over all values.
+    ///
+    /// This includes values in pages that have not been allocated.
+    #[inline]
+    pub fn values(&self) -> impl ExactSizeIterator<Item = &T> + FusedIterator {
+        self.iter().map(|(_, v)| v)
+    }
+
+    /// Returns an iterator over the keys and values in allocated pages.
+    #[inline]
+    pub fn iter_allocated(&self) -> AllocatedIter<'_, L, T> {
+        AllocatedIter {
+            pages: self.pages.iter(),
+            base: 0,
+            values: [].iter().enumerate(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns a mutable iterator over the keys and values in allocated pages.
+    #[inline]
+    pub fn iter_allocated_mut(&mut self) -> AllocatedIterMut<'_, L, T> {
+        AllocatedIterMut {
+            pages: self.pages.iter_mut(),
+            base: 0,
+            values: [].iter_mut().enumerate(),
+            _phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn get_linear(&self, i: usize) -> &T {
+        match self.pages.get(&(i / Self::PAGE_SIZE)) {
+            Some(page) => &page[i % Self::PAGE_SIZE],
+            None => &self.default,
+        }
+    }
+
+    fn get_linear_mut(&mut self, i: usize) -> &mut T
+    where
+        T: Clone,
+    {
+        let page = i / Self::PAGE_SIZE;
+        let default = &self.default;
+        let page = self.pages.entry(page).or_insert_with(|| {
+            let start = page * Self::PAGE_SIZE;
+            let len = Self::PAGE_SIZE.min(L::LENGTH - start);
+            (0..len).map(|_| default.clone()).collect()
+        });
+        &mut page[i % Self::PAGE_SIZE]
+    }
+
+    #[inline]
+    fn num_pages() -> usize {
+        L::LENGTH.div_ceil(Self::PAGE_SIZE)
+    }
+}
+
+impl<L, T> Default for PagedStaticMap<L, T>
+where
+    L: Linearize,
+    T: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L, T> Clone for PagedStaticMap<L, T>
+where
+    L: Linearize,
+    T: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            default: self.default.clone(),
+            pages: self.pages.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// Formats the default value and the keys and values in allocated pages.
+impl<L, T> Debug for PagedStaticMap<L, T>
+where
+    L: Linearize + Debug,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        struct Allocated<'a, L, T>(&'a PagedStaticMap<L, T>)
+        where
+            L: Linearize;
+
+        impl<L, T> Debug for Allocated<'_, L, T>
+        where
+            L: Linearize + Debug,
+            T: Debug,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                f.debug_map().entries(self.0.iter_allocated()).finish()
+            }
+        }
+
+        f.debug_struct("PagedStaticMap")
+            .field("default", &self.default)
+            .field("allocated", &Allocated(self))
+            .finish()
+    }
+}
+
+/// Compares the values of all keys, regardless of which pages have been allocated.
+impl<L, T> PartialEq for PagedStaticMap<L, T>
+where
+    L: Linearize,
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        let mut union = self.pages.len();
+        for (idx, page) in &self.pages {
+            let eq = match other.pages.get(idx) {
+                Some(other) => page == other,
+                None => page.iter().all(|v| *v == other.default),
+            };
+            if !eq {
+                return false;
+            }
+        }
+        for (idx, page) in &other.pages {
+            if self.pages.contains_key(idx) {
+                continue;
+            }
+            union += 1;
+            if page.iter().any(|v| *v != self.default) {
+                return false;
+            }
+        }
+        union == Self::num_pages() || self.default == other.default
+    }
+}
+
+impl<L, T> Eq for PagedStaticMap<L, T>
+where
+    L: Linearize,
+    T: Eq,
+{
+}
+
+impl<L, T> Index<&'_ L> for PagedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: &L) -> &Self::Output {
+        self.get(index)
+    }
+}
+
+impl<L, T> Index<L> for PagedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: L) -> &Self::Output {
+        self.get(&index)
+    }
+}
+
+impl<L, T> Index<Linearized<L>> for PagedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Linearized<L>) -> &Self::Output {
+        self.get_linear(index.get())
+    }
+}
+
+impl<L, T> IndexMut<&'_ L> for PagedStaticMap<L, T>
+where
+    L: Linearize,
+    T: Clone,
+{
+    #[inline]
+    fn index_mut(&mut self, index: &L) -> &mut Self::Output {
+        self.get_mut(index)
+    }
+}
+
+impl<L, T> IndexMut<L> for PagedStaticMap<L, T>
+where
+    L: Linearize,
+    T: Clone,
+{
+    #[inline]
+    fn index_mut(&mut self, index: L) -> &mut Self::Output {
+        self.get_mut(&index)
+    }
+}
+
+impl<L, T> IndexMut<Linearized<L>> for PagedStaticMap<L, T>
+where
+    L: Linearize,
+    T: Clone,
+{
+    #[inline]
+    fn index_mut(&mut self, index: Linearized<L>) -> &mut Self::Output {
+        self.get_linear_mut(index.get())
+    }
+}
+
+impl<L, T> FromIterator<(L, T)> for PagedStaticMap<L, T>
+where
+    L: Linearize,
+    T: Default + Clone,
+{
+    fn from_iter<I: IntoIterator<Item = (L, T)>>(iter: I) -> Self {
+        let mut res = Self::new();
+        res.extend(iter);
+        res
+    }
+}
+
+impl<L, T> Extend<(L, T)> for PagedStaticMap<L, T>
+where
+    L: Linearize,
+    T: Clone,
+{
+    fn extend<I: IntoIterator<Item = (L, T)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            *self.get_mut(&k) = v;
+        }
+    }
+}
+
+impl<'a, L, T> IntoIterator for &'a PagedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    type Item = (L, &'a T);
+    type IntoIter = PagedIter<'a, L, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over all keys and values of a [`PagedStaticMap`].
+///
+/// See [`PagedStaticMap::iter`].
+pub struct PagedIter<'a, L, T>
+where
+    L: Linearize,
+{
+    map: &'a PagedStaticMap<L, T>,
+    next: usize,
+    page: Option<&'a [T]>,
+    page_end: usize,
+}
+
+impl<L, T> Clone for PagedIter<'_, L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map,
+            next: self.next,
+            page: self.page,
+            page_end: self.page_end,
+        }
+    }
+}
+
+impl<'a, L, T> Iterator for PagedIter<'a, L, T>
+where
+    L: Linearize,
+{
+    type Item = (L, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.next;
+        if i >= L::LENGTH {
+            return None;
+        }
+        let page_size = PagedStaticMap::<L, T>::PAGE_SIZE;
+        if i >= self.page_end {
+            let page = i / page_size;
+            self.page = self.map.pages.get(&page).map(|p| &**p);
+            self.page_end = (page * page_size).saturating_add(page_size);
+        }
+        let value = match self.page {
+            Some(page) => &page[i % page_size],
+            None => &self.map.default,
+        };
+        self.next += 1;
+        let key = unsafe {
+            // SAFETY: i < L::LENGTH.
+            L::from_linear_unchecked(i)
+        };
+        Some((key, value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = L::LENGTH - self.next;
+        (len, Some(len))
+    }
+}
+
+impl<L, T> ExactSizeIterator for PagedIter<'_, L, T> where L: Linearize {}
+
+impl<L, T> FusedIterator for PagedIter<'_, L, T> where L: Linearize {}
+
+/// An iterator over the keys and values in the allocated pages of a [`PagedStaticMap`].
+///
+/// See [`PagedStaticMap::iter_allocated`].
+pub struct AllocatedIter<'a, L, T>
+where
+    L: Linearize,
+{
+    pages: btree_map::Iter<'a, usize, Box<[T]>>,
+    base: usize,
+    values: Enumerate<slice::Iter<'a, T>>,
+    _phantom: PhantomData<fn() -> L>,
+}
+
+impl<'a, L, T> Iterator for AllocatedIter<'a, L, T>
+where
+    L: Linearize,
+{
+    type Item = (L, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((offset, value)) = self.values.next() {
+                let key = unsafe {
+                    // SAFETY: Pages only contain keys less than L::LENGTH.
+                    L::from_linear_unchecked(self.base + offset)
+                };
+                return Some((key, value));
+            }
+            let (&page, values) = self.pages.next()?;
+            self.base = page * PagedStaticMap::<L, T>::PAGE_SIZE;
+            self.values = values.iter().enumerate();
+        }
+    }
+}
+
+impl<L, T> FusedIterator for AllocatedIter<'_, L, T> where L: Linearize {}
+
+/// A mutable iterator over the keys and values in the allocated pages of a
+/// [`PagedStaticMap`].
+///
+/// See [`PagedStaticMap::iter_allocated_mut`].
+pub struct AllocatedIterMut<'a, L, T>
+where
+    L: Linearize,
+{
+    pages: btree_map::IterMut<'a, usize, Box<[T]>>,
+    base: usize,
+    values: Enumerate<slice::IterMut<'a, T>>,
+    _phantom: PhantomData<fn() -> L>,
+}
+
+impl<'a, L, T> Iterator for AllocatedIterMut<'a, L, T>
+where
+    L: Linearize,
+{
+    type Item = (L, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((offset, value)) = self.values.next() {
+                let key = unsafe {
+                    // SAFETY: Pages only contain keys less than L::LENGTH.
+                    L::from_linear_unchecked(self.base + offset)
+                };
+                return Some((key, value));
+            }
+            let (&page, values) = self.pages.next()?;
+            self.base = page * PagedStaticMap::<L, T>::PAGE_SIZE;
+            self.values = values.iter_mut().enumerate();
+        }
+    }
+}
+
+impl<L, T> FusedIterator for AllocatedIterMut<'_, L, T> where L: Linearize {}
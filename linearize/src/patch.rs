@@ -0,0 +1,369 @@
+use {
+    crate::{map::iters::Iter, Linearize, LinearizeExt, StaticMap},
+    core::{
+        fmt::{Debug, Formatter},
+        hash::{Hash, Hasher},
+        iter::FusedIterator,
+        mem, slice,
+    },
+};
+
+/// A set of changes to a [`StaticMap`].
+///
+/// A patch contains a new value for some of the keys of a map. All other keys are left
+/// unchanged when the patch is applied.
+///
+/// With the `serde-1` feature, a patch uses the wire format of `HashMap<L, T>` that only
+/// contains the changed keys. This is the same format as
+/// [`skip_none`](crate::serde_1::skip_none).
+///
+/// # Example
+///
+/// ```rust
+/// # use std::cmp::Ordering;
+/// # use linearize::{StaticMap, StaticMapPatch};
+/// let old = StaticMap([1, 2, 3]);
+/// let new = StaticMap([1, 5, 3]);
+/// let patch = StaticMapPatch::from_diff(&old, &new);
+/// assert_eq!(patch.len(), 1);
+/// assert_eq!(patch.get(&Ordering::Equal), Some(&5));
+///
+/// let mut map = old.clone();
+/// let inverse = map.invert(&patch);
+/// map.apply(patch);
+/// assert_eq!(map, new);
+/// map.apply(inverse);
+/// assert_eq!(map, old);
+/// ```
+pub struct StaticMapPatch<L, T>
+where
+    L: Linearize,
+{
+    changes: StaticMap<L, Option<T>>,
+}
+
+impl<L, T> StaticMap<L, T>
+where
+    L: Linearize,
+{
+    /// Returns an iterator over the keys whose values differ between `self` and `other`.
+    ///
+    /// The iterator yields the key, the value in `self`, and the value in `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::StaticMap;
+    /// let a = StaticMap([1, 2]);
+    /// let b = StaticMap([1, 3]);
+    /// assert_eq!(a.diff(&b).collect::<Vec<_>>(), [(true, &2, &3)]);
+    /// ```
+    #[inline]
+    pub fn diff<'a>(&'a self, other: &'a Self) -> Diff<'a, L, T>
+    where
+        T: PartialEq,
+    {
+        Diff {
+            iter: self.iter(),
+            other: other.values(),
+        }
+    }
+
+    /// Applies a patch to this map.
+    ///
+    /// Returns the previous values of the changed keys as a patch. Applying the returned
+    /// patch restores the original map.
+    #[inline]
+    pub fn apply(&mut self, patch: StaticMapPatch<L, T>) -> StaticMapPatch<L, T> {
+        let mut changes = patch.changes;
+        for (value, change) in self.values_mut().zip(changes.values_mut()) {
+            if let Some(change) = change {
+                mem::swap(value, change);
+            }
+        }
+        StaticMapPatch { changes }
+    }
+
+    /// Returns the patch that reverts `patch` after it has been applied to this map.
+    #[inline]
+    pub fn invert(&self, patch: &StaticMapPatch<L, T>) -> StaticMapPatch<L, T>
+    where
+        T: Clone,
+    {
+        StaticMapPatch {
+            changes: StaticMap::from_fn(|l: L| {
+                let l = l.linearized();
+                patch.changes[l].as_ref().map(|_| self[l].clone())
+            }),
+        }
+    }
+}
+
+impl<L, T> StaticMapPatch<L, T>
+where
+    L: Linearize,
+{
+    /// Creates an empty patch.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            changes: StaticMap::default(),
+        }
+    }
+
+    /// Creates a patch that turns `old` into `new`.
+    ///
+    /// The patch contains exactly the keys whose values differ between the two maps.
+    #[inline]
+    pub fn from_diff(old: &StaticMap<L, T>, new: &StaticMap<L, T>) -> Self
+    where
+        T: Clone + PartialEq,
+    {
+        Self {
+            changes: StaticMap::from_fn(|l: L| {
+                let l = l.linearized();
+                (old[l] != new[l]).then(|| new[l].clone())
+            }),
+        }
+    }
+
+    /// Returns the new value of `key` if the patch changes it.
+    #[inline]
+    pub fn get(&self, key: &L) -> Option<&T> {
+        self.changes[key].as_ref()
+    }
+
+    /// Sets the new value of `key` and returns the previous new value.
+    #[inline]
+    pub fn set(&mut self, key: &L, value: T) -> Option<T> {
+        self.changes[key].replace(value)
+    }
+
+    /// Removes `key` from the patch and returns its new value.
+    #[inline]
+    pub fn remove(&mut self, key: &L) -> Option<T> {
+        self.changes[key].take()
+    }
+
+    /// Returns whether the patch changes `key`.
+    #[inline]
+    pub fn contains_key(&self, key: &L) -> bool {
+        self.changes[key].is_some()
+    }
+
+    /// Returns the number of keys changed by this patch.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.changes.values().filter(|v| v.is_some()).count()
+    }
+
+    /// Returns whether the patch does not change any key.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.changes.values().all(|v| v.is_none())
+    }
+
+    /// Returns an iterator over the changed keys and their new values.
+    #[inline]
+    pub fn iter(&self) -> PatchIter<'_, L, T> {
+        PatchIter {
+            iter: self.changes.iter(),
+        }
+    }
+
+    /// Returns the changes of this patch.
+    #[inline]
+    pub fn changes(&self) -> &StaticMap<L, Option<T>> {
+        &self.changes
+    }
+
+    /// Converts this patch into its changes.
+    #[inline]
+    pub fn into_changes(self) -> StaticMap<L, Option<T>> {
+        self.changes
+    }
+}
+
+impl<L, T> Default for StaticMapPatch<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L, T> Clone for StaticMapPatch<L, T>
+where
+    L: Linearize,
+    T: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            changes: self.changes.clone(),
+        }
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.changes.clone_from(&source.changes);
+    }
+}
+
+impl<L, T> PartialEq for StaticMapPatch<L, T>
+where
+    L: Linearize,
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.changes == other.changes
+    }
+}
+
+impl<L, T> Eq for StaticMapPatch<L, T>
+where
+    L: Linearize,
+    T: Eq,
+{
+}
+
+impl<L, T> Hash for StaticMapPatch<L, T>
+where
+    L: Linearize,
+    T: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.changes.hash(state);
+    }
+}
+
+/// Formats the changed keys and their new values.
+impl<L, T> Debug for StaticMapPatch<L, T>
+where
+    L: Linearize + Debug,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<L, T> From<StaticMap<L, Option<T>>> for StaticMapPatch<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn from(changes: StaticMap<L, Option<T>>) -> Self {
+        Self { changes }
+    }
+}
+
+impl<L, T> FromIterator<(L, T)> for StaticMapPatch<L, T>
+where
+    L: Linearize,
+{
+    fn from_iter<I: IntoIterator<Item = (L, T)>>(iter: I) -> Self {
+        let mut res = Self::new();
+        res.extend(iter);
+        res
+    }
+}
+
+impl<L, T> Extend<(L, T)> for StaticMapPatch<L, T>
+where
+    L: Linearize,
+{
+    fn extend<I: IntoIterator<Item = (L, T)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.changes[k] = Some(v);
+        }
+    }
+}
+
+impl<'a, L, T> IntoIterator for &'a StaticMapPatch<L, T>
+where
+    L: Linearize,
+{
+    type Item = (L, &'a T);
+    type IntoIter = PatchIter<'a, L, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the keys whose values differ between two [`StaticMap`]s.
+///
+/// See [`StaticMap::diff`].
+pub struct Diff<'a, L, T>
+where
+    L: Linearize,
+{
+    iter: Iter<'a, L, T>,
+    other: slice::Iter<'a, T>,
+}
+
+impl<'a, L, T> Iterator for Diff<'a, L, T>
+where
+    L: Linearize,
+    T: PartialEq,
+{
+    type Item = (L, &'a T, &'a T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        for (k, v) in &mut self.iter {
+            let other = self.other.next()?;
+            if v != other {
+                return Some((k, v, other));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<L, T> FusedIterator for Diff<'_, L, T>
+where
+    L: Linearize,
+    T: PartialEq,
+{
+}
+
+/// An iterator over the changes of a [`StaticMapPatch`].
+///
+/// See [`StaticMapPatch::iter`].
+pub struct PatchIter<'a, L, T>
+where
+    L: Linearize,
+{
+    iter: Iter<'a, L, Option<T>>,
+}
+
+impl<'a, L, T> Iterator for PatchIter<'a, L, T>
+where
+    L: Linearize,
+{
+    type Item = (L, &'a T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.find_map(|(k, v)| Some((k, v.as_ref()?)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<L, T> FusedIterator for PatchIter<'_, L, T> where L: Linearize {}
@@ -0,0 +1,503 @@
+use {
+    crate::{Linearize, LinearizeExt, Linearized, StaticCopyMap, StaticMap},
+    core::{
+        fmt::{Debug, Display, Formatter},
+        hash::{Hash, Hasher},
+    },
+};
+
+/// The error returned when a map that was expected to be a bijection maps two keys to
+/// the same value.
+///
+/// See [`StaticMap::try_invert`] and [`Permutation::try_from_map`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Collision<L, T> {
+    /// The value that both keys map to.
+    pub value: T,
+    /// The first key that maps to `value`.
+    pub first: L,
+    /// The second key that maps to `value`.
+    pub second: L,
+}
+
+impl<L, T> Display for Collision<L, T>
+where
+    L: Debug,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Keys {:?} and {:?} both map to {:?}",
+            self.first, self.second, self.value,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<L, T> std::error::Error for Collision<L, T>
+where
+    L: Debug,
+    T: Debug,
+{
+}
+
+impl<L, T> StaticMap<L, T>
+where
+    L: Linearize,
+    T: Linearize,
+{
+    /// Inverts this map.
+    ///
+    /// `L` and `T` must have the same [`LENGTH`](Linearize::LENGTH). This is checked at
+    /// compile time.
+    ///
+    /// If two keys map to the same value, the first such collision is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::{static_copy_map, Collision, StaticCopyMap};
+    /// let map: StaticCopyMap<bool, bool> = static_copy_map! {
+    ///     false => true,
+    ///     true => false,
+    /// };
+    /// let inverse = map.try_invert().unwrap();
+    /// assert_eq!(inverse[true], false);
+    ///
+    /// let map: StaticCopyMap<bool, bool> = static_copy_map! {
+    ///     _ => true,
+    /// };
+    /// let collision = map.try_invert().unwrap_err();
+    /// assert_eq!(collision, Collision { value: true, first: false, second: true });
+    /// ```
+    pub fn try_invert(&self) -> Result<StaticMap<T, L>, Collision<L, T>> {
+        const {
+            assert!(L::LENGTH == T::LENGTH);
+        }
+        let mut res = StaticMap::<T, Option<Linearized<L>>>::default();
+        for (k, v) in self.iter() {
+            let slot = &mut res[v];
+            if let Some(first) = slot {
+                return Err(Collision {
+                    value: v.linearized().delinearize(),
+                    first: first.delinearize(),
+                    second: k,
+                });
+            }
+            *slot = Some(k.linearized());
+        }
+        Ok(res.map_values(|v| {
+            // The map is injective and both types have the same length. Therefore it is
+            // also surjective.
+            v.unwrap().delinearize()
+        }))
+    }
+}
+
+/// A permutation of the values of a [Linearize] type.
+///
+/// A permutation is a bijection from `L` to `L`.
+///
+/// # Example
+///
+/// ```rust
+/// # use linearize::{static_map, Linearize, Permutation};
+/// #[derive(Linearize, Copy, Clone, Debug, PartialEq)]
+/// enum Color {
+///     Red,
+///     Green,
+///     Blue,
+/// }
+///
+/// let rotate = Permutation::from_fn(|c| match c {
+///     Color::Red => Color::Green,
+///     Color::Green => Color::Blue,
+///     Color::Blue => Color::Red,
+/// })
+/// .unwrap();
+/// assert_eq!(rotate.get(&Color::Red), Color::Green);
+/// assert_eq!(rotate.inverse().get(&Color::Red), Color::Blue);
+/// assert!(rotate.is_even());
+///
+/// let map = rotate.apply(static_map! {
+///     Color::Red => "r",
+///     Color::Green => "g",
+///     Color::Blue => "b",
+/// });
+/// assert_eq!(map[Color::Green], "r");
+/// ```
+pub struct Permutation<L>
+where
+    L: Linearize,
+{
+    map: StaticCopyMap<L, Linearized<L>>,
+}
+
+impl<L> Permutation<L>
+where
+    L: Linearize,
+{
+    /// Returns the identity permutation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::Permutation;
+    /// let id = Permutation::<bool>::identity();
+    /// assert_eq!(id.get(&true), true);
+    /// ```
+    pub fn identity() -> Self {
+        Self {
+            map: StaticCopyMap::from_fn(|l: L| l.linearized()),
+        }
+    }
+
+    /// Creates a permutation from a callback.
+    ///
+    /// If the callback maps two values to the same value, the first such collision is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::Permutation;
+    /// let not = Permutation::from_fn(|b: bool| !b).unwrap();
+    /// assert_eq!(not.get(&false), true);
+    ///
+    /// assert!(Permutation::from_fn(|_: bool| true).is_err());
+    /// ```
+    pub fn from_fn(cb: impl FnMut(L) -> L) -> Result<Self, Collision<L, L>> {
+        Self::try_from_map(&StaticMap::from_fn(cb))
+    }
+
+    /// Creates a permutation from a map.
+    ///
+    /// If the map maps two keys to the same value, the first such collision is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::{static_map, Permutation};
+    /// let not = Permutation::try_from_map(&static_map! {
+    ///     false => true,
+    ///     true => false,
+    /// })
+    /// .unwrap();
+    /// assert_eq!(not.get(&false), true);
+    /// ```
+    pub fn try_from_map(map: &StaticMap<L, L>) -> Result<Self, Collision<L, L>> {
+        map.try_invert().map(|_| Self {
+            map: map.each_ref().map_values(|v| v.linearized()),
+        })
+    }
+
+    /// Returns the image of a value under this permutation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::Permutation;
+    /// let not = Permutation::from_fn(|b: bool| !b).unwrap();
+    /// assert_eq!(not.get(&true), false);
+    /// ```
+    pub fn get(&self, value: &L) -> L {
+        self.map[value].delinearize()
+    }
+
+    /// Returns the image of a linearized value under this permutation.
+    pub fn get_linearized(&self, value: Linearized<L>) -> Linearized<L> {
+        self.map[value]
+    }
+
+    /// Returns the permutation that first applies `other` and then `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::Permutation;
+    /// let not = Permutation::from_fn(|b: bool| !b).unwrap();
+    /// assert_eq!(not.compose(&not), Permutation::identity());
+    /// ```
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            map: other.map.map_values(|v| self.map[v]),
+        }
+    }
+
+    /// Returns the inverse of this permutation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::Permutation;
+    /// let not = Permutation::from_fn(|b: bool| !b).unwrap();
+    /// assert_eq!(not.inverse(), not);
+    /// ```
+    pub fn inverse(&self) -> Self {
+        let mut map = self.map;
+        for (k, v) in self.map.iter() {
+            map[*v] = k.linearized();
+        }
+        Self { map }
+    }
+
+    /// Returns an iterator over the cycles of this permutation.
+    ///
+    /// Every value of `L` is contained in exactly one cycle. In particular, fixed points
+    /// are returned as cycles of length 1. Each cycle starts at its smallest element and
+    /// the cycles are ordered by their first elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::Permutation;
+    /// let p = Permutation::from_fn(|v: u8| match v {
+    ///     0 => 2,
+    ///     2 => 0,
+    ///     _ => v,
+    /// })
+    /// .unwrap();
+    /// let mut cycles = p.cycles();
+    /// assert!(cycles.next().unwrap().eq([0, 2]));
+    /// assert!(cycles.next().unwrap().eq([1]));
+    /// assert!(cycles.next().unwrap().eq([3]));
+    /// ```
+    pub fn cycles(&self) -> Cycles<'_, L> {
+        Cycles {
+            permutation: self,
+            visited: StaticMap::default(),
+            next: 0,
+        }
+    }
+
+    /// Returns whether this permutation can be written as an even number of
+    /// transpositions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::Permutation;
+    /// assert!(Permutation::<bool>::identity().is_even());
+    /// assert!(!Permutation::from_fn(|b: bool| !b).unwrap().is_even());
+    /// ```
+    pub fn is_even(&self) -> bool {
+        (L::LENGTH - self.cycles().count()) % 2 == 0
+    }
+
+    /// Returns whether this permutation can be written as an odd number of
+    /// transpositions.
+    pub fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
+    /// Moves the value of each key `k` in the map to the key `self.get(&k)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::{static_map, Permutation};
+    /// let not = Permutation::from_fn(|b: bool| !b).unwrap();
+    /// let map = not.apply(static_map! {
+    ///     false => "f",
+    ///     true => "t",
+    /// });
+    /// assert_eq!(map[false], "t");
+    /// ```
+    pub fn apply<T>(&self, map: StaticMap<L, T>) -> StaticMap<L, T> {
+        let inverse = self.inverse();
+        let mut map = map.map_values(Some);
+        StaticMap::from_fn(|k: L| {
+            // inverse is a bijection, therefore each value is taken exactly once.
+            map[inverse.map[k]].take().unwrap()
+        })
+    }
+
+    /// Returns the underlying map.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::Permutation;
+    /// let not = Permutation::from_fn(|b: bool| !b).unwrap();
+    /// assert_eq!(not.to_map()[false], true);
+    /// ```
+    pub fn to_map(&self) -> StaticMap<L, L> {
+        self.map.into_static_map().map_values(|v| v.delinearize())
+    }
+
+    /// Returns an iterator over all permutations of `L` in lexicographic order.
+    ///
+    /// The iterator yields `L::LENGTH!` permutations and should therefore only be used
+    /// with small types.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # use linearize::Permutation;
+    /// assert_eq!(Permutation::<Ordering>::all().count(), 6);
+    /// ```
+    pub fn all() -> Permutations<L> {
+        Permutations {
+            next: Some(Self::identity()),
+        }
+    }
+}
+
+impl<L> Copy for Permutation<L> where L: Linearize {}
+
+impl<L> Clone for Permutation<L>
+where
+    L: Linearize,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L> PartialEq for Permutation<L>
+where
+    L: Linearize,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<L> Eq for Permutation<L> where L: Linearize {}
+
+impl<L> Hash for Permutation<L>
+where
+    L: Linearize,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.map.hash(state)
+    }
+}
+
+impl<L> Default for Permutation<L>
+where
+    L: Linearize,
+{
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<L> Debug for Permutation<L>
+where
+    L: Linearize + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut map = f.debug_map();
+        for (k, v) in self.map.iter() {
+            map.key(&k).value(&v.delinearize());
+        }
+        map.finish()
+    }
+}
+
+/// An iterator over the cycles of a [Permutation].
+///
+/// Construct it with [`Permutation::cycles`].
+pub struct Cycles<'a, L>
+where
+    L: Linearize,
+{
+    permutation: &'a Permutation<L>,
+    visited: StaticMap<L, bool>,
+    next: usize,
+}
+
+impl<'a, L> Iterator for Cycles<'a, L>
+where
+    L: Linearize,
+{
+    type Item = Cycle<'a, L>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next < L::LENGTH {
+            let start = unsafe {
+                // SAFETY: self.next < L::LENGTH.
+                Linearized::new_unchecked(self.next)
+            };
+            self.next += 1;
+            if self.visited[start] {
+                continue;
+            }
+            let mut current = start;
+            loop {
+                self.visited[current] = true;
+                current = self.permutation.map[current];
+                if current == start {
+                    break;
+                }
+            }
+            return Some(Cycle {
+                permutation: self.permutation,
+                start,
+                current: Some(start),
+            });
+        }
+        None
+    }
+}
+
+/// An iterator over the elements of a cycle of a [Permutation].
+///
+/// This type is yielded by [Cycles].
+pub struct Cycle<'a, L>
+where
+    L: Linearize,
+{
+    permutation: &'a Permutation<L>,
+    start: Linearized<L>,
+    current: Option<Linearized<L>>,
+}
+
+impl<L> Iterator for Cycle<'_, L>
+where
+    L: Linearize,
+{
+    type Item = L;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current?;
+        let next = self.permutation.map[current];
+        self.current = (next != self.start).then_some(next);
+        Some(current.delinearize())
+    }
+}
+
+/// An iterator over all permutations of a [Linearize] type.
+///
+/// Construct it with [`Permutation::all`].
+pub struct Permutations<L>
+where
+    L: Linearize,
+{
+    next: Option<Permutation<L>>,
+}
+
+impl<L> Iterator for Permutations<L>
+where
+    L: Linearize,
+{
+    type Item = Permutation<L>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let res = self.next?;
+        let mut next = res;
+        let values: &mut [Linearized<L>] = next.map.as_mut();
+        // Standard algorithm for the lexicographically next permutation.
+        self.next = None;
+        if let Some(pivot) = values.windows(2).rposition(|w| w[0] < w[1]) {
+            let successor = values.iter().rposition(|v| *v > values[pivot]).unwrap();
+            values.swap(pivot, successor);
+            values[pivot + 1..].reverse();
+            self.next = Some(next);
+        }
+        Some(res)
+    }
+}
@@ -0,0 +1,187 @@
+use {
+    crate::{Linearize, StaticMap},
+    core::mem::{self, ManuallyDrop},
+};
+
+/// Types that are the product of two linearizable types.
+///
+/// The [derive macro](linearize_derive::Linearize) implements this trait for all structs
+/// with exactly two fields. For such a struct, `First` is the type of the first field
+/// and `Second` is the type of the second field.
+///
+/// Given such a type `P`, a `StaticMap<P, T>` has the same layout as a
+/// `StaticMap<P::First, StaticMap<P::Second, T>>`. The functions
+/// [`curry`](StaticMap::curry) and [`uncurry`](StaticMap::uncurry) convert between
+/// the two without copying.
+///
+/// # Example
+///
+/// ```rust
+/// # use linearize::{Linearize, StaticMap};
+/// #[derive(Linearize)]
+/// struct Pos {
+///     row: bool,
+///     col: bool,
+/// }
+///
+/// let map = StaticMap::from_fn(|p: Pos| (p.row as u8) << 1 | p.col as u8);
+/// let map: StaticMap<bool, StaticMap<bool, u8>> = map.curry();
+/// assert_eq!(map[true][false], 2);
+/// let map: StaticMap<Pos, u8> = map.uncurry();
+/// assert_eq!(map[Pos { row: true, col: false }], 2);
+/// ```
+///
+/// # Safety
+///
+/// - [`Self::LENGTH`](Linearize::LENGTH) must be `First::LENGTH * Second::LENGTH`.
+/// - For each value `p` of this type that is composed of `first` and `second`,
+///   `p.linearize()` must be `first.linearize() * Second::LENGTH + second.linearize()`.
+pub unsafe trait LinearizeProduct: Linearize {
+    /// The type of the more significant component.
+    type First: Linearize;
+
+    /// The type of the less significant component.
+    type Second: Linearize;
+}
+
+const fn assert_same_layout<P, T>()
+where
+    P: LinearizeProduct,
+{
+    assert!(P::LENGTH == P::First::LENGTH * P::Second::LENGTH);
+    assert!(
+        size_of::<StaticMap<P, T>>() == size_of::<StaticMap<P::First, StaticMap<P::Second, T>>>()
+    );
+}
+
+impl<P, T> StaticMap<P, T>
+where
+    P: LinearizeProduct,
+{
+    /// Converts this map to a nested map keyed by the components of `P`.
+    ///
+    /// This is a zero-cost conversion.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::{Linearize, StaticMap};
+    /// #[derive(Linearize)]
+    /// struct Pos(bool, bool);
+    ///
+    /// let map = StaticMap::from_fn(|p: Pos| p.0 && !p.1);
+    /// let map = map.curry();
+    /// assert!(map[true][false]);
+    /// assert!(!map[true][true]);
+    /// ```
+    #[inline]
+    pub fn curry(self) -> StaticMap<P::First, StaticMap<P::Second, T>> {
+        const { assert_same_layout::<P, T>() };
+        let slf = ManuallyDrop::new(self);
+        unsafe {
+            // SAFETY:
+            // - StaticMap<P, T> is [T; P::LENGTH].
+            // - StaticMap<P::First, StaticMap<P::Second, T>> is
+            //   [[T; P::Second::LENGTH]; P::First::LENGTH].
+            // - P::LENGTH = P::First::LENGTH * P::Second::LENGTH by the requirements of
+            //   LinearizeProduct, so both types have the same layout.
+            // - The element at index (f, s) of the nested array is at offset
+            //   f * P::Second::LENGTH + s, which is the linearization of the value of P
+            //   composed of f and s.
+            mem::transmute_copy(&*slf)
+        }
+    }
+
+    /// Converts a reference to this map to a reference to a nested map keyed by the
+    /// components of `P`.
+    ///
+    /// This is a zero-cost re-interpretation conversion.
+    #[inline]
+    pub fn as_curried(&self) -> &StaticMap<P::First, StaticMap<P::Second, T>> {
+        const { assert_same_layout::<P, T>() };
+        unsafe {
+            // SAFETY: See the documentation of curry.
+            &*(self as *const Self as *const StaticMap<P::First, StaticMap<P::Second, T>>)
+        }
+    }
+
+    /// Converts a mutable reference to this map to a mutable reference to a nested map
+    /// keyed by the components of `P`.
+    ///
+    /// This is a zero-cost re-interpretation conversion.
+    #[inline]
+    pub fn as_curried_mut(&mut self) -> &mut StaticMap<P::First, StaticMap<P::Second, T>> {
+        const { assert_same_layout::<P, T>() };
+        unsafe {
+            // SAFETY: See the documentation of curry.
+            &mut *(self as *mut Self as *mut StaticMap<P::First, StaticMap<P::Second, T>>)
+        }
+    }
+}
+
+impl<A, B, T> StaticMap<A, StaticMap<B, T>>
+where
+    A: Linearize,
+    B: Linearize,
+{
+    /// Converts this nested map to a map keyed by the product `P` of `A` and `B`.
+    ///
+    /// This is a zero-cost conversion.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use linearize::{Linearize, StaticMap};
+    /// #[derive(Linearize)]
+    /// struct Pos(bool, bool);
+    ///
+    /// let map = StaticMap::from_fn(|a: bool| StaticMap::from_fn(|b: bool| a && !b));
+    /// let map: StaticMap<Pos, bool> = map.uncurry();
+    /// assert!(map[Pos(true, false)]);
+    /// assert!(!map[Pos(true, true)]);
+    /// ```
+    #[inline]
+    pub fn uncurry<P>(self) -> StaticMap<P, T>
+    where
+        P: LinearizeProduct<First = A, Second = B>,
+    {
+        const { assert_same_layout::<P, T>() };
+        let slf = ManuallyDrop::new(self);
+        unsafe {
+            // SAFETY: See the documentation of curry.
+            mem::transmute_copy(&*slf)
+        }
+    }
+
+    /// Converts a reference to this nested map to a reference to a map keyed by the
+    /// product `P` of `A` and `B`.
+    ///
+    /// This is a zero-cost re-interpretation conversion.
+    #[inline]
+    pub fn as_uncurried<P>(&self) -> &StaticMap<P, T>
+    where
+        P: LinearizeProduct<First = A, Second = B>,
+    {
+        const { assert_same_layout::<P, T>() };
+        unsafe {
+            // SAFETY: See the documentation of curry.
+            &*(self as *const Self as *const StaticMap<P, T>)
+        }
+    }
+
+    /// Converts a mutable reference to this nested map to a mutable reference to a map
+    /// keyed by the product `P` of `A` and `B`.
+    ///
+    /// This is a zero-cost re-interpretation conversion.
+    #[inline]
+    pub fn as_uncurried_mut<P>(&mut self) -> &mut StaticMap<P, T>
+    where
+        P: LinearizeProduct<First = A, Second = B>,
+    {
+        const { assert_same_layout::<P, T>() };
+        unsafe {
+            // SAFETY: See the documentation of curry.
+            &mut *(self as *mut Self as *mut StaticMap<P, T>)
+        }
+    }
+}
@@ -0,0 +1,605 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use {
+    crate::{Linearize, StaticMap},
+    core::{
+        fmt::{Debug, Display, Formatter},
+        iter::FusedIterator,
+        marker::PhantomData,
+        mem,
+    },
+};
+
+const TOP: u32 = 1 << 24;
+const MAX_TOTAL: u32 = 1 << 16;
+const ADAPTIVE_INCREMENT: u32 = 24;
+
+/// An error returned by [`RangeEncoder`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RangeError {
+    /// The output buffer is full.
+    BufferFull,
+    /// The model assigns a frequency of zero to the encoded symbol.
+    ZeroFrequency,
+    /// The total frequency of the model is zero or exceeds `2^16`.
+    InvalidTotal,
+}
+
+impl Display for RangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RangeError::BufferFull => f.write_str("The output buffer is full"),
+            RangeError::ZeroFrequency => f.write_str("The symbol has a frequency of zero"),
+            RangeError::InvalidTotal => f.write_str("The total frequency is out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RangeError {}
+
+/// A destination for the bytes produced by a [`RangeEncoder`].
+///
+/// This trait is implemented for `&mut [u8]`, which advances the slice after each byte
+/// like `std::io::Write`, and for `Vec<u8>` with the `alloc` feature.
+pub trait ByteSink {
+    /// Appends a byte.
+    fn write_byte(&mut self, byte: u8) -> Result<(), RangeError>;
+}
+
+impl ByteSink for &mut [u8] {
+    #[inline]
+    fn write_byte(&mut self, byte: u8) -> Result<(), RangeError> {
+        let (first, rest) = mem::take(self)
+            .split_first_mut()
+            .ok_or(RangeError::BufferFull)?;
+        *first = byte;
+        *self = rest;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ByteSink for Vec<u8> {
+    #[inline]
+    fn write_byte(&mut self, byte: u8) -> Result<(), RangeError> {
+        self.push(byte);
+        Ok(())
+    }
+}
+
+/// A model that assigns frequencies to the values of `L`.
+///
+/// The frequency of a symbol determines how many bits are used to encode it. The encoder
+/// and the decoder must use identical models.
+///
+/// The crate provides [`StaticModel`] and [`AdaptiveModel`].
+pub trait FrequencyModel<L> {
+    /// Returns the sum of all frequencies.
+    ///
+    /// This must be between `1` and `2^16`.
+    fn total(&self) -> u32;
+
+    /// Returns the sum of the frequencies of all symbols before `symbol` and the
+    /// frequency of `symbol`.
+    fn range(&self, symbol: &L) -> (u32, u32);
+
+    /// Returns the symbol whose range contains `target` and its range.
+    ///
+    /// `target` is less than [`total`](Self::total).
+    fn find(&self, target: u32) -> (L, u32, u32);
+
+    /// Updates the model after `symbol` has been encoded or decoded.
+    #[inline]
+    fn update(&mut self, symbol: &L) {
+        let _ = symbol;
+    }
+}
+
+/// A frequency model that never changes.
+///
+/// Frequencies whose sum exceeds `2^16` are scaled down. Symbols with a non-zero
+/// frequency keep a non-zero frequency. Symbols with a frequency of zero cannot be
+/// encoded.
+///
+/// `L::LENGTH` must not exceed `2^15`. This is checked at compile time.
+pub struct StaticModel<L>
+where
+    L: Linearize,
+{
+    freqs: StaticMap<L, u32>,
+    ends: StaticMap<L, u32>,
+}
+
+impl<L> StaticModel<L>
+where
+    L: Linearize,
+{
+    /// Creates a model from a frequency table.
+    ///
+    /// Returns `None` if all frequencies are zero.
+    pub fn new(mut freqs: StaticMap<L, u32>) -> Option<Self> {
+        const {
+            assert!(L::LENGTH <= MAX_TOTAL as usize / 2);
+        }
+        let total: u64 = freqs.values().map(|&f| f as u64).sum();
+        if total == 0 {
+            return None;
+        }
+        if total > MAX_TOTAL as u64 {
+            let target = (MAX_TOTAL as usize - L::LENGTH) as u64;
+            for f in freqs.values_mut() {
+                if *f > 0 {
+                    *f = (*f as u64 * target / total).max(1) as u32;
+                }
+            }
+        }
+        let mut ends = freqs.clone();
+        let mut end = 0;
+        for e in ends.values_mut() {
+            end += *e;
+            *e = end;
+        }
+        Some(Self { freqs, ends })
+    }
+
+    /// Returns the frequency table of this model.
+    ///
+    /// The table differs from the table passed to [`new`](Self::new) if it had to be
+    /// scaled down.
+    #[inline]
+    pub fn frequencies(&self) -> &StaticMap<L, u32> {
+        &self.freqs
+    }
+}
+
+impl<L> FrequencyModel<L> for StaticModel<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn total(&self) -> u32 {
+        self.ends.last().copied().unwrap_or_default()
+    }
+
+    #[inline]
+    fn range(&self, symbol: &L) -> (u32, u32) {
+        let freq = self.freqs[symbol];
+        (self.ends[symbol] - freq, freq)
+    }
+
+    #[inline]
+    fn find(&self, target: u32) -> (L, u32, u32) {
+        let freqs: &[_] = &self.freqs;
+        let ends: &[_] = &self.ends;
+        let idx = ends.partition_point(|&end| end <= target);
+        let freq = freqs[idx];
+        let symbol = unsafe {
+            // SAFETY: target < total and the last element of ends is the total.
+            //         Therefore, idx < L::LENGTH.
+            L::from_linear_unchecked(idx)
+        };
+        (symbol, ends[idx] - freq, freq)
+    }
+}
+
+impl<L> Clone for StaticModel<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            freqs: self.freqs.clone(),
+            ends: self.ends.clone(),
+        }
+    }
+}
+
+impl<L> Debug for StaticModel<L>
+where
+    L: Linearize + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("StaticModel").field(&self.freqs).finish()
+    }
+}
+
+/// A frequency model that learns the frequencies of the symbols.
+///
+/// All symbols start with the same frequency. Each time a symbol is encoded or decoded,
+/// its frequency is increased. If the sum of all frequencies exceeds `2^16`, all
+/// frequencies are halved, which lets the model adapt to changing statistics.
+///
+/// The cumulative frequencies are stored in a Fenwick tree. Encoding and decoding a
+/// symbol therefore take time proportional to `log(L::LENGTH)`, except when the
+/// frequencies are halved.
+///
+/// `L::LENGTH` must not exceed `2^15`. This is checked at compile time.
+pub struct AdaptiveModel<L>
+where
+    L: Linearize,
+{
+    freqs: StaticMap<L, u32>,
+    tree: StaticMap<L, u32>,
+    total: u32,
+}
+
+impl<L> AdaptiveModel<L>
+where
+    L: Linearize,
+{
+    /// Creates a model that assigns the same frequency to every symbol.
+    #[inline]
+    pub fn new() -> Self {
+        const {
+            assert!(L::LENGTH <= MAX_TOTAL as usize / 2);
+        }
+        let freqs = StaticMap::from_fn(|_| 1);
+        Self {
+            tree: Self::build_tree(&freqs),
+            freqs,
+            total: L::LENGTH as u32,
+        }
+    }
+
+    /// Returns the current frequency table of this model.
+    #[inline]
+    pub fn frequencies(&self) -> &StaticMap<L, u32> {
+        &self.freqs
+    }
+
+    /// Builds a Fenwick tree in which element `i` contains the sum of the frequencies
+    /// in `(i + 1 - lowbit(i + 1))..=i`.
+    fn build_tree(freqs: &StaticMap<L, u32>) -> StaticMap<L, u32> {
+        let mut tree = freqs.clone();
+        let tree_mut: &mut [u32] = tree.as_mut();
+        for j in 1..=L::LENGTH {
+            let parent = j + (j & j.wrapping_neg());
+            if parent <= L::LENGTH {
+                tree_mut[parent - 1] += tree_mut[j - 1];
+            }
+        }
+        tree
+    }
+}
+
+impl<L> FrequencyModel<L> for AdaptiveModel<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn total(&self) -> u32 {
+        self.total
+    }
+
+    fn range(&self, symbol: &L) -> (u32, u32) {
+        let tree: &[u32] = self.tree.as_ref();
+        let idx = symbol.linearize();
+        let mut start = 0;
+        let mut j = idx;
+        while j > 0 {
+            start += tree[j - 1];
+            j &= j - 1;
+        }
+        (start, self.freqs[symbol])
+    }
+
+    fn find(&self, target: u32) -> (L, u32, u32) {
+        let tree: &[u32] = self.tree.as_ref();
+        let mut idx = 0;
+        let mut rest = target;
+        let mut step = match L::LENGTH {
+            0 => 0,
+            n => 1 << n.ilog2(),
+        };
+        while step > 0 {
+            if idx + step <= L::LENGTH && tree[idx + step - 1] <= rest {
+                idx += step;
+                rest -= tree[idx - 1];
+            }
+            step >>= 1;
+        }
+        assert!(
+            idx < L::LENGTH,
+            "target must be less than the total frequency"
+        );
+        let freqs: &[u32] = self.freqs.as_ref();
+        let symbol = unsafe {
+            // SAFETY: We just checked that idx < L::LENGTH.
+            L::from_linear_unchecked(idx)
+        };
+        (symbol, target - rest, freqs[idx])
+    }
+
+    fn update(&mut self, symbol: &L) {
+        self.freqs[symbol] += ADAPTIVE_INCREMENT;
+        self.total += ADAPTIVE_INCREMENT;
+        if self.total > MAX_TOTAL {
+            self.total = 0;
+            for f in self.freqs.values_mut() {
+                *f = f.div_ceil(2);
+                self.total += *f;
+            }
+            self.tree = Self::build_tree(&self.freqs);
+            return;
+        }
+        let tree: &mut [u32] = self.tree.as_mut();
+        let mut j = symbol.linearize() + 1;
+        while j <= L::LENGTH {
+            tree[j - 1] += ADAPTIVE_INCREMENT;
+            j += j & j.wrapping_neg();
+        }
+    }
+}
+
+impl<L> Default for AdaptiveModel<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L> Clone for AdaptiveModel<L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            freqs: self.freqs.clone(),
+            tree: self.tree.clone(),
+            total: self.total,
+        }
+    }
+}
+
+impl<L> Debug for AdaptiveModel<L>
+where
+    L: Linearize + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("AdaptiveModel").field(&self.freqs).finish()
+    }
+}
+
+/// An encoder that compresses sequences of [`Linearize`] values.
+///
+/// Each symbol is encoded with a [`FrequencyModel`]. Frequent symbols use fewer bits
+/// than rare symbols. The output must be decoded with a [`RangeDecoder`] that uses the
+/// same sequence of models.
+///
+/// The number of encoded symbols is not part of the output and must be transmitted
+/// separately.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::cmp::Ordering;
+/// # use linearize::{AdaptiveModel, RangeDecoder, RangeEncoder};
+/// let symbols = [Ordering::Less; 100];
+/// let mut encoder = RangeEncoder::new(Vec::new());
+/// encoder.encode_all(&mut AdaptiveModel::new(), symbols).unwrap();
+/// let bytes = encoder.finish().unwrap();
+/// assert!(bytes.len() < 16);
+///
+/// let decoder = RangeDecoder::new(&bytes);
+/// let decoded: Vec<Ordering> = decoder.decode_iter(AdaptiveModel::new(), 100).collect();
+/// assert_eq!(decoded, symbols);
+/// ```
+pub struct RangeEncoder<W> {
+    low: u64,
+    range: u32,
+    cache: u8,
+    cache_size: u64,
+    out: W,
+}
+
+impl<W> RangeEncoder<W>
+where
+    W: ByteSink,
+{
+    /// Creates an encoder that writes to `out`.
+    #[inline]
+    pub fn new(out: W) -> Self {
+        Self {
+            low: 0,
+            range: u32::MAX,
+            cache: 0,
+            cache_size: 1,
+            out,
+        }
+    }
+
+    /// Encodes a symbol and updates the model.
+    ///
+    /// Returns [`RangeError::InvalidTotal`] if the total frequency of the model is not
+    /// between `1` and `2^16`. If an error occurs, the state of the encoder is
+    /// unspecified.
+    pub fn encode<L, M>(&mut self, model: &mut M, symbol: &L) -> Result<(), RangeError>
+    where
+        M: FrequencyModel<L> + ?Sized,
+    {
+        let total = model.total();
+        if total == 0 || total > MAX_TOTAL {
+            return Err(RangeError::InvalidTotal);
+        }
+        let (start, freq) = model.range(symbol);
+        if freq == 0 {
+            return Err(RangeError::ZeroFrequency);
+        }
+        let r = self.range / total;
+        self.low += r as u64 * start as u64;
+        self.range = r * freq;
+        while self.range < TOP {
+            self.range <<= 8;
+            self.shift_low()?;
+        }
+        model.update(symbol);
+        Ok(())
+    }
+
+    /// Encodes all symbols of an iterator with the same model.
+    ///
+    /// If an error occurs, the state of the encoder is unspecified.
+    pub fn encode_all<L, M>(
+        &mut self,
+        model: &mut M,
+        symbols: impl IntoIterator<Item = L>,
+    ) -> Result<(), RangeError>
+    where
+        M: FrequencyModel<L> + ?Sized,
+    {
+        for symbol in symbols {
+            self.encode(model, &symbol)?;
+        }
+        Ok(())
+    }
+
+    /// Flushes the encoder and returns the output.
+    pub fn finish(mut self) -> Result<W, RangeError> {
+        for _ in 0..5 {
+            self.shift_low()?;
+        }
+        Ok(self.out)
+    }
+
+    fn shift_low(&mut self) -> Result<(), RangeError> {
+        if (self.low as u32) < 0xff00_0000 || self.low > u32::MAX as u64 {
+            let carry = (self.low >> 32) as u8;
+            let mut byte = self.cache;
+            loop {
+                self.out.write_byte(byte.wrapping_add(carry))?;
+                byte = 0xff;
+                self.cache_size -= 1;
+                if self.cache_size == 0 {
+                    break;
+                }
+            }
+            self.cache = (self.low >> 24) as u8;
+        }
+        self.cache_size += 1;
+        self.low = (self.low & 0x00ff_ffff) << 8;
+        Ok(())
+    }
+}
+
+/// A decoder for the output of a [`RangeEncoder`].
+///
+/// Malformed input does not cause errors or panics but produces unspecified symbols.
+/// Bytes after the end of the input are treated as zeros.
+#[derive(Clone, Debug)]
+pub struct RangeDecoder<'a> {
+    input: &'a [u8],
+    code: u32,
+    range: u32,
+}
+
+impl<'a> RangeDecoder<'a> {
+    /// Creates a decoder that reads from `input`.
+    pub fn new(input: &'a [u8]) -> Self {
+        let mut slf = Self {
+            input,
+            code: 0,
+            range: u32::MAX,
+        };
+        for _ in 0..5 {
+            slf.code = (slf.code << 8) | slf.next_byte() as u32;
+        }
+        slf
+    }
+
+    /// Decodes a symbol and updates the model.
+    pub fn decode<L, M>(&mut self, model: &mut M) -> L
+    where
+        M: FrequencyModel<L> + ?Sized,
+    {
+        let total = model.total();
+        debug_assert!(
+            (1..=MAX_TOTAL).contains(&total),
+            "the total frequency must be between 1 and 2^16",
+        );
+        let r = self.range / total;
+        let target = (self.code / r).min(total - 1);
+        let (symbol, start, freq) = model.find(target);
+        self.code -= r * start;
+        self.range = r * freq;
+        while self.range < TOP {
+            self.code = (self.code << 8) | self.next_byte() as u32;
+            self.range <<= 8;
+        }
+        model.update(&symbol);
+        symbol
+    }
+
+    /// Returns an iterator that decodes `len` symbols with the same model.
+    #[inline]
+    pub fn decode_iter<L, M>(self, model: M, len: usize) -> Decode<'a, L, M>
+    where
+        M: FrequencyModel<L>,
+    {
+        Decode {
+            decoder: self,
+            model,
+            len,
+            _phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn next_byte(&mut self) -> u8 {
+        match self.input.split_first() {
+            Some((&b, rest)) => {
+                self.input = rest;
+                b
+            }
+            None => 0,
+        }
+    }
+}
+
+/// An iterator that decodes symbols.
+///
+/// See [`RangeDecoder::decode_iter`].
+pub struct Decode<'a, L, M> {
+    decoder: RangeDecoder<'a>,
+    model: M,
+    len: usize,
+    _phantom: PhantomData<fn() -> L>,
+}
+
+impl<L, M> Decode<'_, L, M> {
+    /// Returns the model in its current state.
+    #[inline]
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+}
+
+impl<L, M> Iterator for Decode<'_, L, M>
+where
+    M: FrequencyModel<L>,
+{
+    type Item = L;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(self.decoder.decode(&mut self.model))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<L, M> ExactSizeIterator for Decode<'_, L, M> where M: FrequencyModel<L> {}
+
+impl<L, M> FusedIterator for Decode<'_, L, M> where M: FrequencyModel<L> {}
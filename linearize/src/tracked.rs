@@ -0,0 +1,575 @@
+use {
+    crate::{
+        map::iters::{Iter, IterMut},
+        Linearize, Linearized, StaticMap,
+    },
+    alloc::boxed::Box,
+    core::{
+        fmt::{Debug, Formatter},
+        iter::FusedIterator,
+        marker::PhantomData,
+        mem,
+        ops::{Deref, Index, IndexMut},
+        slice,
+    },
+};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A [`StaticMap`] that records which keys have been written to.
+///
+/// Every mutable access to a value marks its key as dirty. This includes
+/// [`IndexMut`], [`get_mut`](Self::get_mut), [`iter_mut`](Self::iter_mut), and
+/// [`values_mut`](Self::values_mut). The iterators only mark the keys that they
+/// actually yield.
+///
+/// The dirty keys are stored in a bitset with one bit per key.
+///
+/// This type dereferences to `StaticMap<L, T>` for read-only access.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::cmp::Ordering;
+/// # use linearize::{StaticMap, TrackedStaticMap};
+/// let mut map = TrackedStaticMap::new(StaticMap::<Ordering, i32>::default());
+/// map[Ordering::Less] = 1;
+/// map[Ordering::Greater] = 0;
+/// assert_eq!(map.dirty_keys().collect::<Vec<_>>(), [Ordering::Less, Ordering::Greater]);
+/// let snapshot = StaticMap::default();
+/// assert_eq!(map.changes_since(&snapshot).collect::<Vec<_>>(), [(Ordering::Less, &1)]);
+/// map.clear_dirty();
+/// assert_eq!(map.dirty_keys().count(), 0);
+/// ```
+pub struct TrackedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    map: StaticMap<L, T>,
+    dirty: Box<[u64]>,
+}
+
+impl<L, T> TrackedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    const WORDS: usize = L::LENGTH.div_ceil(BITS);
+
+    /// Creates a new tracked map with no dirty keys.
+    #[inline]
+    pub fn new(map: StaticMap<L, T>) -> Self {
+        Self {
+            map,
+            dirty: alloc::vec![0; Self::WORDS].into_boxed_slice(),
+        }
+    }
+
+    /// Returns the underlying map.
+    #[inline]
+    pub fn as_map(&self) -> &StaticMap<L, T> {
+        &self.map
+    }
+
+    /// Returns the underlying map and discards the dirty keys.
+    #[inline]
+    pub fn into_inner(self) -> StaticMap<L, T> {
+        self.map
+    }
+
+    /// Returns a mutable reference to the value of `key` and marks it as dirty.
+    #[inline]
+    pub fn get_mut(&mut self, key: &L) -> &mut T {
+        let key = Linearized::new(key);
+        self.set_dirty(key.get());
+        &mut self.map[key]
+    }
+
+    /// Sets the value of `key`, marks it as dirty, and returns the previous value.
+    #[inline]
+    pub fn set(&mut self, key: &L, value: T) -> T {
+        mem::replace(self.get_mut(key), value)
+    }
+
+    /// Returns a mutable iterator over the keys and values.
+    ///
+    /// Each key is marked as dirty when it is yielded.
+    #[inline]
+    pub fn iter_mut(&mut self) -> TrackedIterMut<'_, L, T> {
+        TrackedIterMut {
+            iter: self.map.iter_mut(),
+            dirty: &mut self.dirty,
+        }
+    }
+
+    /// Returns a mutable iterator over the values.
+    ///
+    /// The key of each value is marked as dirty when the value is yielded.
+    #[inline]
+    pub fn values_mut(&mut self) -> TrackedValuesMut<'_, T> {
+        TrackedValuesMut {
+            iter: self.map.values_mut(),
+            dirty: &mut self.dirty,
+            front: 0,
+        }
+    }
+
+    /// Returns whether `key` is dirty.
+    #[inline]
+    pub fn is_dirty(&self, key: &L) -> bool {
+        let idx = key.linearize();
+        self.dirty[idx / BITS] & (1 << (idx % BITS)) != 0
+    }
+
+    /// Returns whether any key is dirty.
+    #[inline]
+    pub fn has_dirty(&self) -> bool {
+        self.dirty.iter().any(|w| *w != 0)
+    }
+
+    /// Marks `key` as dirty without modifying its value.
+    #[inline]
+    pub fn mark_dirty(&mut self, key: &L) {
+        self.set_dirty(key.linearize());
+    }
+
+    /// Marks all keys as dirty.
+    #[inline]
+    pub fn mark_all_dirty(&mut self) {
+        self.dirty.fill(!0);
+        if L::LENGTH % BITS != 0 {
+            self.dirty[Self::WORDS - 1] = (1 << (L::LENGTH % BITS)) - 1;
+        }
+    }
+
+    /// Returns an iterator over the dirty keys.
+    #[inline]
+    pub fn dirty_keys(&self) -> DirtyKeys<'_, L> {
+        DirtyKeys::new(&self.dirty)
+    }
+
+    /// Returns the dirty keys and marks all keys as clean.
+    #[inline]
+    pub fn take_dirty(&mut self) -> StaticMap<L, bool> {
+        let dirty = StaticMap::from_fn(|l| self.is_dirty(&l));
+        self.clear_dirty();
+        dirty
+    }
+
+    /// Marks all keys as clean.
+    #[inline]
+    pub fn clear_dirty(&mut self) {
+        self.dirty.fill(0);
+    }
+
+    #[inline]
+    fn set_dirty(&mut self, idx: usize) {
+        set_bit(&mut self.dirty, idx);
+    }
+
+    /// Returns an iterator over the keys whose values differ from their values in
+    /// `snapshot`.
+    ///
+    /// Unlike [`dirty_keys`](Self::dirty_keys), this compares the values and therefore
+    /// does not yield keys that were written to without changing their values.
+    #[inline]
+    pub fn changes_since<'a>(&'a self, snapshot: &'a StaticMap<L, T>) -> ChangesSince<'a, L, T>
+    where
+        T: PartialEq,
+    {
+        ChangesSince {
+            iter: self.map.iter(),
+            snapshot: snapshot.values(),
+        }
+    }
+}
+
+impl<L, T> Deref for TrackedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    type Target = StaticMap<L, T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.map
+    }
+}
+
+impl<L, T> Default for TrackedStaticMap<L, T>
+where
+    L: Linearize,
+    T: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new(StaticMap::default())
+    }
+}
+
+impl<L, T> Clone for TrackedStaticMap<L, T>
+where
+    L: Linearize,
+    T: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+            dirty: self.dirty.clone(),
+        }
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.map.clone_from(&source.map);
+        self.dirty.clone_from(&source.dirty);
+    }
+}
+
+impl<L, T> Debug for TrackedStaticMap<L, T>
+where
+    L: Linearize + Debug,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        struct Dirty<'a, L>(DirtyKeys<'a, L>)
+        where
+            L: Linearize;
+
+        impl<L> Debug for Dirty<'_, L>
+        where
+            L: Linearize + Debug,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                f.debug_set().entries(self.0.clone()).finish()
+            }
+        }
+
+        f.debug_struct("TrackedStaticMap")
+            .field("map", &self.map)
+            .field("dirty", &Dirty(self.dirty_keys()))
+            .finish()
+    }
+}
+
+impl<L, T> From<StaticMap<L, T>> for TrackedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn from(value: StaticMap<L, T>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<L, T> Index<&'_ L> for TrackedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: &L) -> &Self::Output {
+        &self.map[index]
+    }
+}
+
+impl<L, T> Index<L> for TrackedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: L) -> &Self::Output {
+        &self.map[index]
+    }
+}
+
+impl<L, T> Index<Linearized<L>> for TrackedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Linearized<L>) -> &Self::Output {
+        &self.map[index]
+    }
+}
+
+impl<L, T> IndexMut<&'_ L> for TrackedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn index_mut(&mut self, index: &L) -> &mut Self::Output {
+        self.get_mut(index)
+    }
+}
+
+impl<L, T> IndexMut<L> for TrackedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn index_mut(&mut self, index: L) -> &mut Self::Output {
+        self.get_mut(&index)
+    }
+}
+
+impl<L, T> IndexMut<Linearized<L>> for TrackedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn index_mut(&mut self, index: Linearized<L>) -> &mut Self::Output {
+        self.set_dirty(index.get());
+        &mut self.map[index]
+    }
+}
+
+impl<L, T> Extend<(L, T)> for TrackedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    fn extend<I: IntoIterator<Item = (L, T)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.set(&k, v);
+        }
+    }
+}
+
+impl<'a, L, T> IntoIterator for &'a TrackedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    type Item = (L, &'a T);
+    type IntoIter = Iter<'a, L, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.iter()
+    }
+}
+
+impl<'a, L, T> IntoIterator for &'a mut TrackedStaticMap<L, T>
+where
+    L: Linearize,
+{
+    type Item = (L, &'a mut T);
+    type IntoIter = TrackedIterMut<'a, L, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// A mutable iterator over the keys and values of a [`TrackedStaticMap`].
+///
+/// See [`TrackedStaticMap::iter_mut`].
+pub struct TrackedIterMut<'a, L, T>
+where
+    L: Linearize,
+{
+    iter: IterMut<'a, L, T>,
+    dirty: &'a mut [u64],
+}
+
+impl<'a, L, T> Iterator for TrackedIterMut<'a, L, T>
+where
+    L: Linearize,
+{
+    type Item = (L, &'a mut T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let res = self.iter.next()?;
+        set_bit(self.dirty, res.0.linearize());
+        Some(res)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<L, T> DoubleEndedIterator for TrackedIterMut<'_, L, T>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let res = self.iter.next_back()?;
+        set_bit(self.dirty, res.0.linearize());
+        Some(res)
+    }
+}
+
+impl<L, T> ExactSizeIterator for TrackedIterMut<'_, L, T> where L: Linearize {}
+
+impl<L, T> FusedIterator for TrackedIterMut<'_, L, T> where L: Linearize {}
+
+/// A mutable iterator over the values of a [`TrackedStaticMap`].
+///
+/// See [`TrackedStaticMap::values_mut`].
+pub struct TrackedValuesMut<'a, T> {
+    iter: slice::IterMut<'a, T>,
+    dirty: &'a mut [u64],
+    front: usize,
+}
+
+impl<'a, T> Iterator for TrackedValuesMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let res = self.iter.next()?;
+        set_bit(self.dirty, self.front);
+        self.front += 1;
+        Some(res)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for TrackedValuesMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let res = self.iter.next_back()?;
+        set_bit(self.dirty, self.front + self.iter.len());
+        Some(res)
+    }
+}
+
+impl<T> ExactSizeIterator for TrackedValuesMut<'_, T> {}
+
+impl<T> FusedIterator for TrackedValuesMut<'_, T> {}
+
+/// An iterator over the dirty keys of a [`TrackedStaticMap`].
+///
+/// See [`TrackedStaticMap::dirty_keys`].
+pub struct DirtyKeys<'a, L>
+where
+    L: Linearize,
+{
+    words: &'a [u64],
+    word: usize,
+    bits: u64,
+    _phantom: PhantomData<fn() -> L>,
+}
+
+impl<'a, L> DirtyKeys<'a, L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn new(words: &'a [u64]) -> Self {
+        Self {
+            words,
+            word: 0,
+            bits: words.first().copied().unwrap_or(0),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<L> Iterator for DirtyKeys<'_, L>
+where
+    L: Linearize,
+{
+    type Item = L;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.bits == 0 {
+            self.word += 1;
+            self.bits = *self.words.get(self.word)?;
+        }
+        let bit = self.bits.trailing_zeros() as usize;
+        self.bits &= self.bits - 1;
+        unsafe {
+            // SAFETY: The bitset only contains bits for indices less than L::LENGTH.
+            Some(L::from_linear_unchecked(self.word * BITS + bit))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.bits.count_ones() as usize
+            + self
+                .words
+                .get(self.word + 1..)
+                .unwrap_or_default()
+                .iter()
+                .map(|w| w.count_ones() as usize)
+                .sum::<usize>();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<L> ExactSizeIterator for DirtyKeys<'_, L> where L: Linearize {}
+
+impl<L> FusedIterator for DirtyKeys<'_, L> where L: Linearize {}
+
+impl<L> Clone for DirtyKeys<'_, L>
+where
+    L: Linearize,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            words: self.words,
+            word: self.word,
+            bits: self.bits,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// An iterator over the changed values of a [`TrackedStaticMap`].
+///
+/// See [`TrackedStaticMap::changes_since`].
+pub struct ChangesSince<'a, L, T>
+where
+    L: Linearize,
+{
+    iter: Iter<'a, L, T>,
+    snapshot: slice::Iter<'a, T>,
+}
+
+impl<'a, L, T> Iterator for ChangesSince<'a, L, T>
+where
+    L: Linearize,
+    T: PartialEq,
+{
+    type Item = (L, &'a T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let snapshot = &mut self.snapshot;
+        self.iter
+            .find(|(_, v)| snapshot.next().is_some_and(|s| *v != s))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<L, T> FusedIterator for ChangesSince<'_, L, T>
+where
+    L: Linearize,
+    T: PartialEq,
+{
+}
+
+#[inline]
+fn set_bit(words: &mut [u64], idx: usize) {
+    words[idx / BITS] |= 1 << (idx % BITS);
+}
@@ -2,7 +2,10 @@
 use crate::LinearizeExt;
 use {
     crate::Linearize,
-    core::{marker::PhantomData, ops::Range},
+    core::{
+        marker::PhantomData,
+        ops::{Bound, Range, RangeBounds},
+    },
 };
 
 /// An iterator over all values of `L`.
@@ -18,13 +21,62 @@ where
     L: Linearize,
 {
     pub(super) fn new() -> Self {
+        Self::from_linear_range(0..L::LENGTH)
+    }
+
+    /// Creates an iterator over all values of `L` starting at `start`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # use linearize::iter::Variants;
+    /// let mut variants = Variants::starting_at(&Ordering::Equal);
+    /// assert_eq!(variants.next(), Some(Ordering::Equal));
+    /// assert_eq!(variants.next(), Some(Ordering::Greater));
+    /// assert_eq!(variants.next(), None);
+    /// ```
+    pub fn starting_at(start: &L) -> Self {
+        Self::from_linear_range(start.linearize()..L::LENGTH)
+    }
+
+    /// Creates an iterator over all values of `L` contained in `range`.
+    ///
+    /// See [`L::variants_in`][LinearizeExt::variants_in].
+    pub fn in_range(range: impl RangeBounds<L>) -> Self {
+        Self::from_linear_range(linear_range(range))
+    }
+
+    /// The range must be contained in 0..L::LENGTH.
+    pub(crate) fn from_linear_range(iter: Range<usize>) -> Self {
         Self {
-            iter: 0..L::LENGTH,
+            iter,
             _phantom: Default::default(),
         }
     }
 }
 
+/// Converts a range of values to the range of their linearizations.
+///
+/// The returned range is contained in `0..L::LENGTH`. If the start of the range is after
+/// its end, the returned range is empty.
+pub(crate) fn linear_range<L>(range: impl RangeBounds<L>) -> Range<usize>
+where
+    L: Linearize + ?Sized,
+{
+    let start = match range.start_bound() {
+        Bound::Included(l) => l.linearize(),
+        Bound::Excluded(l) => l.linearize() + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(l) => l.linearize() + 1,
+        Bound::Excluded(l) => l.linearize(),
+        Bound::Unbounded => L::LENGTH,
+    };
+    start..end.max(start)
+}
+
 impl<L> Clone for Variants<L>
 where
     L: Linearize,